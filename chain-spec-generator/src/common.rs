@@ -17,6 +17,7 @@
 
 use crate::{
 	relay_chain_specs::{KusamaChainSpec, PolkadotChainSpec},
+	runtimes::{find_runtime, supported_runtimes, Runtime},
 	system_parachains_specs::{
 		AssetHubKusamaChainSpec, AssetHubPolkadotChainSpec, BridgeHubKusamaChainSpec,
 		BridgeHubPolkadotChainSpec, CollectivesPolkadotChainSpec, CoretimeKusamaChainSpec,
@@ -25,45 +26,291 @@ use crate::{
 	},
 	ChainSpec,
 };
+use sc_executor::RuntimeVersion;
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use serde_json::Value;
+use std::{
+	fmt,
+	path::{Path, PathBuf},
+};
+
+/// The JSON pointers to the runtime code in plain and raw chain specs.
+pub const CODE_POINTERS: [&str; 2] =
+	["/genesis/runtimeGenesis/code", "/genesis/raw/top/0x3a636f6465"];
+
+/// The JSON pointers to the genesis config in plain chain specs.
+pub const GENESIS_POINTERS: [&str; 2] =
+	["/genesis/runtimeGenesis/patch", "/genesis/runtimeGenesis/config"];
+
+/// An error while loading a chain spec.
+#[derive(Debug)]
+pub enum Error {
+	/// The chain spec file could not be read.
+	Read { path: PathBuf, error: std::io::Error },
+	/// The chain spec is not valid JSON.
+	Parse(serde_json::Error),
+	/// The given runtime is not compiled into this binary.
+	UnsupportedRuntime { name: String, supported: String },
+	/// No compiled runtime matches the chain spec.
+	UnknownRuntime,
+	/// No compiled runtime has the `spec_name` of the runtime code in the chain spec.
+	UnknownSpecName(String),
+	/// Multiple compiled runtimes match the chain spec.
+	AmbiguousRuntime(Vec<&'static str>),
+	/// The chain spec could not be loaded for the detected runtime.
+	ChainSpec(String),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Read { path, error } => write!(f, "Failed to read '{}': {error}", path.display()),
+			Self::Parse(error) => write!(f, "Failed to parse chain spec: {error}"),
+			Self::UnsupportedRuntime { name, supported } =>
+				write!(f, "Unknown runtime: '{name}', only supported: '{supported}'"),
+			Self::UnknownRuntime => write!(
+				f,
+				"Failed to detect the runtime of the chain spec, please pass it with `--runtime`"
+			),
+			Self::UnknownSpecName(spec_name) => write!(
+				f,
+				"The runtime '{spec_name}' of the chain spec code is not compiled into this binary"
+			),
+			Self::AmbiguousRuntime(names) => write!(
+				f,
+				"The chain spec matches the runtimes '{}', please pass one with `--runtime`",
+				names.join(", ")
+			),
+			Self::ChainSpec(error) => write!(f, "Failed to load chain spec: {error}"),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for String {
+	fn from(error: Error) -> Self {
+		error.to_string()
+	}
+}
+
+/// Load the chain spec from the json file at `path`.
+///
+/// The chain spec is loaded for the given `runtime` or the runtime detected by
+/// [`detect_runtime`].
+pub fn from_json_file(path: &Path, runtime: Option<&str>) -> Result<Box<dyn ChainSpec>, Error> {
+	let json = std::fs::read(path).map_err(|error| Error::Read { path: path.into(), error })?;
+	from_json_bytes(json, runtime)
+}
+
+/// Load the chain spec from `json`.
+///
+/// The chain spec is loaded for the given `runtime` or the runtime detected by
+/// [`detect_runtime`].
+pub fn from_json_bytes(json: Vec<u8>, runtime: Option<&str>) -> Result<Box<dyn ChainSpec>, Error> {
+	let runtime = match runtime {
+		Some(name) => find_runtime(name)?,
+		None => detect_runtime(&serde_json::from_slice(&json).map_err(Error::Parse)?)?,
+	};
+
+	load_chain_spec(runtime.name, json).map_err(Error::ChainSpec)
+}
+
+fn load_chain_spec(runtime: &str, json: Vec<u8>) -> Result<Box<dyn ChainSpec>, String> {
+	match runtime {
+		"polkadot" => Ok(Box::new(PolkadotChainSpec::from_json_bytes(json)?)),
+		"kusama" => Ok(Box::new(KusamaChainSpec::from_json_bytes(json)?)),
+		"asset-hub-polkadot" => Ok(Box::new(AssetHubPolkadotChainSpec::from_json_bytes(json)?)),
+		"asset-hub-kusama" => Ok(Box::new(AssetHubKusamaChainSpec::from_json_bytes(json)?)),
+		"collectives-polkadot" =>
+			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_bytes(json)?)),
+		"bridge-hub-polkadot" => Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_bytes(json)?)),
+		"bridge-hub-kusama" => Ok(Box::new(BridgeHubKusamaChainSpec::from_json_bytes(json)?)),
+		"coretime-kusama" => Ok(Box::new(CoretimeKusamaChainSpec::from_json_bytes(json)?)),
+		"coretime-polkadot" => Ok(Box::new(CoretimePolkadotChainSpec::from_json_bytes(json)?)),
+		"glutton-kusama" => Ok(Box::new(GluttonKusamaChainSpec::from_json_bytes(json)?)),
+		"encointer-kusama" => Ok(Box::new(EncointerKusamaChainSpec::from_json_bytes(json)?)),
+		"people-kusama" => Ok(Box::new(PeopleKusamaChainSpec::from_json_bytes(json)?)),
+		"people-polkadot" => Ok(Box::new(PeoplePolkadotChainSpec::from_json_bytes(json)?)),
+		_ => Err(format!("Unknown runtime: '{runtime}'")),
+	}
+}
+
+/// Detect the runtime of `chain_spec`.
+///
+/// This is the compiled runtime with the `spec_name` of the runtime version embedded in the code
+/// of `chain_spec`. Without code, it is the runtime whose `RuntimeGenesisConfig` accepts the
+/// genesis config of the plain `chain_spec`. When this is true for multiple runtimes, the runtime
+/// with the longest name that the chain spec `id` starts with is chosen.
+pub fn detect_runtime(chain_spec: &Value) -> Result<Runtime, Error> {
+	let mut runtimes = supported_runtimes();
+
+	let code = CODE_POINTERS
+		.iter()
+		.find_map(|pointer| chain_spec.pointer(pointer))
+		.and_then(Value::as_str)
+		.and_then(from_hex);
+	if let Some(version) = code.as_deref().and_then(runtime_version) {
+		let spec_name = version.spec_name.to_string();
+		return runtimes
+			.into_iter()
+			.find(|r| (r.spec_name)() == spec_name)
+			.ok_or(Error::UnknownSpecName(spec_name))
+	}
+
+	let genesis = GENESIS_POINTERS
+		.iter()
+		.find_map(|pointer| chain_spec.pointer(pointer))
+		.ok_or(Error::UnknownRuntime)?;
+	runtimes.retain(|r| (r.validate_genesis_config)(genesis).is_ok());
+
+	if runtimes.len() > 1 {
+		let id = chain_spec["id"].as_str().unwrap_or_default();
+		let by_id = runtimes
+			.iter()
+			.enumerate()
+			.filter(|(_, r)| id.starts_with(r.name))
+			.max_by_key(|(_, r)| r.name.len())
+			.map(|(pos, _)| pos);
+
+		if let Some(pos) = by_id {
+			return Ok(runtimes.swap_remove(pos))
+		}
+	}
+
+	match runtimes.len() {
+		0 => Err(Error::UnknownRuntime),
+		1 => Ok(runtimes.remove(0)),
+		_ => Err(Error::AmbiguousRuntime(runtimes.iter().map(|r| r.name).collect())),
+	}
+}
+
+/// Returns the runtime version embedded in the runtime `code`, which may be compressed.
+pub fn runtime_version(code: &[u8]) -> Option<RuntimeVersion> {
+	let blob = RuntimeBlob::uncompress_if_needed(code).ok()?;
+	sc_executor::read_embedded_version(&blob).ok().flatten()
+}
+
+/// Encode `bytes` as `0x` prefixed hex string.
+pub fn to_hex(bytes: &[u8]) -> String {
+	use std::fmt::Write;
 
-#[derive(Debug, serde::Deserialize)]
-struct EmptyChainSpecWithId {
-	id: String,
+	bytes.iter().fold(String::from("0x"), |mut hex, byte| {
+		let _ = write!(hex, "{byte:02x}");
+		hex
+	})
 }
 
-pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn ChainSpec>, String> {
-	let path = std::path::PathBuf::from(&filepath);
-	let file = std::fs::File::open(filepath).expect("Failed to open file");
-	let reader = std::io::BufReader::new(file);
-	let chain_spec: EmptyChainSpecWithId = serde_json::from_reader(reader)
-		.expect("Failed to read 'json' file with ChainSpec configuration");
-	match &chain_spec.id {
-		x if x.starts_with("polkadot") | x.starts_with("dot") =>
-			Ok(Box::new(PolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("kusama") | x.starts_with("ksm") =>
-			Ok(Box::new(KusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("asset-hub-polkadot") =>
-			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("asset-hub-kusama") =>
-			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("collectives-polkadot") =>
-			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("bridge-hub-polkadot") =>
-			Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("bridge-hub-kusama") =>
-			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("coretime-kusama") =>
-			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("coretime-polkadot") =>
-			Ok(Box::new(CoretimePolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("glutton-kusama") =>
-			Ok(Box::new(GluttonKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("encointer-kusama") =>
-			Ok(Box::new(EncointerKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("people-kusama") =>
-			Ok(Box::new(PeopleKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("people-polkadot") =>
-			Ok(Box::new(PeoplePolkadotChainSpec::from_json_file(path)?)),
-		_ => Err(format!("Unknown chain 'id' in json file. Only supported: {supported}'")),
+/// Decode the `0x` prefixed hex string `hex`.
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+	let hex = hex.strip_prefix("0x")?;
+
+	(0..hex.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use serde_json::json;
+
+	/// Returns a wasm module that only embeds the runtime version with the given `spec_name`.
+	fn code_with_spec_name(spec_name: &str) -> String {
+		let version =
+			RuntimeVersion { spec_name: spec_name.to_string().into(), ..Default::default() };
+		let name = b"runtime_version";
+		let section = [&[name.len() as u8][..], name, &version.encode()].concat();
+		assert!(section.len() < 128, "The section size is encoded as a single byte");
+
+		to_hex(&[&b"\0asm\x01\0\0\0"[..], &[0, section.len() as u8], &section].concat())
+	}
+
+	fn chain_spec_with_code(code: String) -> Value {
+		json!({ "genesis": { "runtimeGenesis": { "code": code, "patch": {} } } })
+	}
+
+	#[test]
+	fn detect_runtime_by_code_works() {
+		for runtime in supported_runtimes() {
+			let chain_spec = chain_spec_with_code(code_with_spec_name(&(runtime.spec_name)()));
+			assert_eq!(detect_runtime(&chain_spec).unwrap().name, runtime.name);
+		}
+	}
+
+	#[test]
+	fn detect_runtime_with_unknown_spec_name_fails() {
+		let chain_spec = chain_spec_with_code(code_with_spec_name("westend"));
+		assert!(matches!(
+			detect_runtime(&chain_spec),
+			Err(Error::UnknownSpecName(spec_name)) if spec_name == "westend"
+		));
+	}
+
+	#[test]
+	fn detect_runtime_without_code_or_genesis_config_fails() {
+		assert!(matches!(detect_runtime(&json!({})), Err(Error::UnknownRuntime)));
+
+		// Code without an embedded runtime version can't be used for the detection.
+		let chain_spec =
+			json!({ "genesis": { "raw": { "top": { "0x3a636f6465": "0x0061736d01000000" } } } });
+		assert!(matches!(detect_runtime(&chain_spec), Err(Error::UnknownRuntime)));
+
+		let chain_spec =
+			json!({ "genesis": { "runtimeGenesis": { "patch": { "unknownPallet": {} } } } });
+		assert!(matches!(detect_runtime(&chain_spec), Err(Error::UnknownRuntime)));
+	}
+
+	#[cfg(feature = "polkadot")]
+	#[test]
+	fn detect_runtime_by_genesis_config_works() {
+		let chain_spec =
+			json!({ "id": "polkadot-local", "genesis": { "runtimeGenesis": { "patch": {} } } });
+		assert_eq!(detect_runtime(&chain_spec).unwrap().name, "polkadot");
+	}
+
+	#[cfg(all(feature = "polkadot", feature = "kusama"))]
+	#[test]
+	fn detect_runtime_with_ambiguous_genesis_config_fails() {
+		let chain_spec = json!({ "id": "local", "genesis": { "runtimeGenesis": { "patch": {} } } });
+		assert!(matches!(
+			detect_runtime(&chain_spec),
+			Err(Error::AmbiguousRuntime(names))
+				if names.contains(&"polkadot") && names.contains(&"kusama")
+		));
+	}
+
+	#[test]
+	fn load_errors_work() {
+		assert!(matches!(
+			from_json_file(Path::new("/non/existing/chain-spec.json"), None),
+			Err(Error::Read { .. })
+		));
+		assert!(matches!(from_json_bytes(b"{".to_vec(), None), Err(Error::Parse(_))));
+		assert!(matches!(
+			from_json_bytes(b"{}".to_vec(), Some("westend")),
+			Err(Error::UnsupportedRuntime { name, .. }) if name == "westend"
+		));
+	}
+
+	#[cfg(feature = "polkadot")]
+	#[test]
+	fn load_invalid_chain_spec_fails() {
+		assert!(matches!(
+			from_json_bytes(b"{}".to_vec(), Some("polkadot")),
+			Err(Error::ChainSpec(_))
+		));
+	}
+
+	#[test]
+	fn hex_works() {
+		assert_eq!(to_hex(&[0, 1, 0xab, 0xff]), "0x0001abff");
+		assert_eq!(from_hex("0x0001abff"), Some(vec![0, 1, 0xab, 0xff]));
+		assert_eq!(from_hex("0x"), Some(vec![]));
+		assert_eq!(from_hex("0001"), None);
+		assert_eq!(from_hex("0x001"), None);
+		assert_eq!(from_hex("0xzz"), None);
 	}
 }
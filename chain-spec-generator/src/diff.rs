@@ -0,0 +1,184 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	common::{from_hex, CODE_POINTERS, GENESIS_POINTERS},
+	runtimes::find_runtime,
+	SupportedChains,
+};
+use serde_json::{json, Value};
+use sp_core::{hashing::blake2_256, H256};
+
+/// Something that can be compared.
+enum Source {
+	/// A full chain spec.
+	ChainSpec(Value),
+	/// A genesis config preset of a runtime.
+	Preset(Value),
+}
+
+/// Returns the differences between the chain specs or presets `a` and `b`.
+///
+/// `a` and `b` can be a supported chain, a json file or a genesis config preset given as
+/// `<runtime>:<preset>`. When a chain spec is compared against a preset, only the genesis config
+/// of the chain spec is compared.
+pub fn diff_sources(
+	supported_chains: &SupportedChains,
+	a: &str,
+	b: &str,
+) -> Result<Vec<Value>, String> {
+	let (a, b) = match (load(supported_chains, a)?, load(supported_chains, b)?) {
+		(Source::ChainSpec(a), Source::ChainSpec(b)) | (Source::Preset(a), Source::Preset(b)) =>
+			(a, b),
+		(Source::ChainSpec(a), Source::Preset(b)) => (genesis_config(a)?, b),
+		(Source::Preset(a), Source::ChainSpec(b)) => (a, genesis_config(b)?),
+	};
+
+	Ok(diff(&a, &b))
+}
+
+/// Load the chain spec or preset given by `source`.
+fn load(supported_chains: &SupportedChains, source: &str) -> Result<Source, String> {
+	let chain_spec = if source.ends_with(".json") {
+		std::fs::read_to_string(source).map_err(|e| format!("Failed to read '{source}': {e}"))?
+	} else if let Some(function) = supported_chains.get(source) {
+		(*function)()?.as_json(false)?
+	} else if let Some((runtime, preset)) = source.split_once(':') {
+		let preset = (find_runtime(runtime)?.get_preset)(preset)
+			.ok_or_else(|| format!("Unknown preset '{preset}' of runtime '{runtime}'"))?;
+		return Ok(Source::Preset(preset))
+	} else {
+		return Err(format!(
+			"Unknown chain: '{source}', only supported: a chain, a json file or `<runtime>:<preset>`"
+		))
+	};
+
+	let mut chain_spec = serde_json::from_str::<Value>(&chain_spec)
+		.map_err(|e| format!("Failed to parse chain spec '{source}': {e}"))?;
+	summarize_code(&mut chain_spec);
+
+	Ok(Source::ChainSpec(chain_spec))
+}
+
+/// Returns the genesis config of the given plain `chain_spec`.
+fn genesis_config(mut chain_spec: Value) -> Result<Value, String> {
+	GENESIS_POINTERS
+		.iter()
+		.find_map(|pointer| chain_spec.pointer_mut(pointer).map(Value::take))
+		.ok_or_else(|| "Only plain chain specs can be compared against a preset".into())
+}
+
+/// Replace the runtime code in `chain_spec` by its size and hash to keep the diff readable.
+///
+/// The hash is the `blake2_256` hash of the code, the same hash the chain uses for its code.
+fn summarize_code(chain_spec: &mut Value) {
+	for pointer in CODE_POINTERS {
+		if let Some(Value::String(code)) = chain_spec.pointer_mut(pointer) {
+			let bytes = from_hex(code).unwrap_or_else(|| code.as_bytes().to_vec());
+			*code =
+				format!("<{} bytes of code with hash {:?}>", bytes.len(), H256(blake2_256(&bytes)));
+		}
+	}
+}
+
+/// Returns the differences between `left` and `right`.
+///
+/// Every difference is an object with the JSON pointer `path` to the differing value, the `kind`
+/// of the difference (`added`, `removed` or `changed`) and the `left` and/or `right` value.
+pub fn diff(left: &Value, right: &Value) -> Vec<Value> {
+	let mut diffs = Vec::new();
+	diff_at(String::new(), left, right, &mut diffs);
+	diffs
+}
+
+fn diff_at(path: String, left: &Value, right: &Value, diffs: &mut Vec<Value>) {
+	match (left, right) {
+		(Value::Object(l), Value::Object(r)) => {
+			for (key, l_value) in l {
+				let path = format!("{path}/{}", escape(key));
+				match r.get(key) {
+					Some(r_value) => diff_at(path, l_value, r_value, diffs),
+					None => diffs.push(json!({ "path": path, "kind": "removed", "left": l_value })),
+				}
+			}
+			for (key, r_value) in r.iter().filter(|(key, _)| !l.contains_key(*key)) {
+				let path = format!("{path}/{}", escape(key));
+				diffs.push(json!({ "path": path, "kind": "added", "right": r_value }));
+			}
+		},
+		(Value::Array(l), Value::Array(r)) => {
+			for (i, (l_value, r_value)) in l.iter().zip(r).enumerate() {
+				diff_at(format!("{path}/{i}"), l_value, r_value, diffs);
+			}
+			for (i, l_value) in l.iter().enumerate().skip(r.len()) {
+				diffs.push(
+					json!({ "path": format!("{path}/{i}"), "kind": "removed", "left": l_value }),
+				);
+			}
+			for (i, r_value) in r.iter().enumerate().skip(l.len()) {
+				diffs.push(
+					json!({ "path": format!("{path}/{i}"), "kind": "added", "right": r_value }),
+				);
+			}
+		},
+		(l, r) if l != r =>
+			diffs.push(json!({ "path": path, "kind": "changed", "left": l, "right": r })),
+		_ => {},
+	}
+}
+
+/// Escape `key` to be used as a reference token in a JSON pointer.
+fn escape(key: &str) -> String {
+	key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn diff_works() {
+		let left = json!({ "a": 1, "b": { "c/d": [1, 2, 3] }, "e": "x" });
+		let right = json!({ "a": 2, "b": { "c/d": [1, 5] }, "f": null });
+
+		assert_eq!(
+			diff(&left, &right),
+			vec![
+				json!({ "path": "/a", "kind": "changed", "left": 1, "right": 2 }),
+				json!({ "path": "/b/c~1d/1", "kind": "changed", "left": 2, "right": 5 }),
+				json!({ "path": "/b/c~1d/2", "kind": "removed", "left": 3 }),
+				json!({ "path": "/e", "kind": "removed", "left": "x" }),
+				json!({ "path": "/f", "kind": "added", "right": null }),
+			]
+		);
+		assert!(diff(&left, &left).is_empty());
+	}
+
+	#[test]
+	fn summarize_code_works() {
+		let mut chain_spec = json!({ "genesis": { "runtimeGenesis": { "code": "0x0001abff" } } });
+		summarize_code(&mut chain_spec);
+
+		assert_eq!(
+			chain_spec.pointer("/genesis/runtimeGenesis/code"),
+			Some(&json!(format!(
+				"<4 bytes of code with hash {:?}>",
+				H256(blake2_256(&[0, 1, 0xab, 0xff]))
+			)))
+		);
+	}
+}
@@ -15,128 +15,234 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use sc_chain_spec::ChainSpec;
-use std::collections::HashMap;
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
 
 mod common;
+mod diff;
+mod network;
+mod patch;
 mod relay_chain_specs;
+mod runtimes;
 mod system_parachains_specs;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+	#[command(subcommand)]
+	command: Option<Command>,
+
 	/// The chain spec to generate.
-	chain: String,
+	#[arg(required = true)]
+	chain: Option<String>,
 
 	/// Generate the chain spec as raw?
 	#[arg(long)]
 	raw: bool,
+
+	/// Apply the JSON merge patch (RFC 7396) in the given file to the genesis config.
+	#[arg(long)]
+	patch: Option<PathBuf>,
+
+	/// Set the value at the dot separated path in the genesis config, e.g.
+	/// `--set configuration.config.maxCodeSize=3145728`.
+	///
+	/// The value is parsed as JSON and used as a string if it is not valid JSON. Applied in the
+	/// given order after `--patch`.
+	#[arg(long = "set", value_name = "PATH=VALUE")]
+	set: Vec<String>,
+
+	/// The runtime of the json file given as `chain`, e.g. `polkadot` or `asset-hub-kusama`.
+	///
+	/// Only required if the runtime can not be detected from the code or the genesis config in
+	/// the json file.
+	#[arg(long)]
+	runtime: Option<String>,
 }
 
+#[derive(Subcommand)]
+enum Command {
+	/// List all chains that are supported by this binary.
+	List,
+	/// List the names of the genesis config presets of the given runtime.
+	Presets {
+		/// The runtime, e.g. `polkadot` or `asset-hub-kusama`.
+		runtime: String,
+	},
+	/// Print the differences between two chain specs as JSON.
+	///
+	/// Both sides can be a supported chain, a json file or a genesis config preset given as
+	/// `<runtime>:<preset>`. When a chain spec is compared against a preset, only the genesis
+	/// config of the chain spec is compared.
+	Diff { a: String, b: String },
+	/// Generate a local network of a relay chain with all its system parachains.
+	///
+	/// Writes the raw chain specs of the relay chain and the system parachains, registered in the
+	/// genesis of the relay chain and connected with HRMP channels, together with a zombienet
+	/// network configuration to the `output` directory.
+	Network {
+		/// The relay chain of the network, `polkadot` or `kusama`.
+		relay: String,
+
+		/// The directory to write the chain specs to.
+		#[arg(long, default_value = "network")]
+		output: PathBuf,
+	},
+}
+
+/// The supported chains by their name.
+pub type SupportedChains =
+	BTreeMap<&'static str, Box<dyn Fn() -> Result<Box<dyn ChainSpec>, String>>>;
+
 fn main() -> Result<(), String> {
 	let cli = Cli::parse();
+	let supported_chains = supported_chains();
 
-	let supported_chains =
-		HashMap::<&str, Box<dyn Fn() -> Result<Box<dyn ChainSpec>, String>>>::from([
-			#[cfg(feature = "polkadot")]
-			("polkadot-dev", Box::new(relay_chain_specs::polkadot_development_config) as Box<_>),
-			#[cfg(feature = "polkadot")]
-			(
-				"polkadot-local",
-				Box::new(relay_chain_specs::polkadot_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "kusama")]
-			("kusama-dev", Box::new(relay_chain_specs::kusama_development_config) as Box<_>),
-			#[cfg(feature = "kusama")]
-			("kusama-local", Box::new(relay_chain_specs::kusama_local_testnet_config) as Box<_>),
-			#[cfg(feature = "asset-hub-kusama")]
-			(
-				"asset-hub-kusama-local",
-				Box::new(system_parachains_specs::asset_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "asset-hub-polkadot")]
-			(
-				"asset-hub-polkadot-local",
-				Box::new(system_parachains_specs::asset_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "collectives-polkadot")]
-			(
-				"collectives-polkadot-local",
-				Box::new(system_parachains_specs::collectives_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "bridge-hub-polkadot")]
-			(
-				"bridge-hub-polkadot-local",
-				Box::new(system_parachains_specs::bridge_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "bridge-hub-kusama")]
-			(
-				"bridge-hub-kusama-local",
-				Box::new(system_parachains_specs::bridge_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "glutton-kusama")]
-			(
-				"glutton-kusama-local",
-				Box::new(system_parachains_specs::glutton_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "encointer-kusama")]
-			(
-				"encointer-kusama-local",
-				Box::new(system_parachains_specs::encointer_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-kusama")]
-			(
-				"coretime-kusama",
-				Box::new(system_parachains_specs::coretime_kusama_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-kusama")]
-			(
-				"coretime-kusama-local",
-				Box::new(system_parachains_specs::coretime_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-polkadot")]
-			(
-				"coretime-polkadot",
-				Box::new(system_parachains_specs::coretime_polkadot_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-polkadot")]
-			(
-				"coretime-polkadot-local",
-				Box::new(system_parachains_specs::coretime_polkadot_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "people-kusama")]
-			(
-				"people-kusama-local",
-				Box::new(system_parachains_specs::people_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "people-polkadot")]
-			(
-				"people-polkadot-local",
-				Box::new(system_parachains_specs::people_polkadot_local_testnet_config) as Box<_>,
-			),
-		]);
-
-	if let Some(function) = supported_chains.get(&*cli.chain) {
-		let chain_spec = (*function)()?.as_json(cli.raw)?;
-		print!("{chain_spec}");
-		Ok(())
-	} else {
-		let supported = supported_chains.keys().enumerate().fold(String::new(), |c, (n, k)| {
-			let extra = if n + 1 < supported_chains.len() { ", " } else { "" };
-			format!("{c}{k}{extra}")
-		});
-		if cli.chain.ends_with(".json") {
-			let chain_spec = common::from_json_file(&cli.chain, supported)?.as_json(cli.raw)?;
-			print!("{chain_spec}");
+	match cli.command {
+		Some(Command::List) => {
+			supported_chains.keys().for_each(|chain| println!("{chain}"));
+			Ok(())
+		},
+		Some(Command::Presets { runtime }) => {
+			let runtime = runtimes::find_runtime(&runtime)?;
+			(runtime.preset_names)().iter().for_each(|preset| println!("{preset}"));
 			Ok(())
+		},
+		Some(Command::Diff { a, b }) => {
+			let diff = diff::diff_sources(&supported_chains, &a, &b)?;
+			let diff = serde_json::to_string_pretty(&diff).map_err(|e| e.to_string())?;
+			println!("{diff}");
+			Ok(())
+		},
+		Some(Command::Network { relay, output }) =>
+			network::generate_network(&supported_chains, &relay, &output),
+		None => {
+			let chain = cli.chain.expect("`chain` is required without a subcommand; qed");
+			let patches = cli
+				.patch
+				.iter()
+				.map(|path| patch::read_patch_file(path))
+				.chain(cli.set.iter().map(|arg| patch::parse_set(arg)))
+				.collect::<Result<Vec<_>, _>>()?;
+			generate_chain_spec(&supported_chains, &chain, cli.runtime.as_deref(), cli.raw, patches)
+		},
+	}
+}
+
+/// Returns all chains that are compiled into this binary.
+fn supported_chains() -> SupportedChains {
+	SupportedChains::from([
+		#[cfg(feature = "polkadot")]
+		("polkadot-dev", Box::new(relay_chain_specs::polkadot_development_config) as Box<_>),
+		#[cfg(feature = "polkadot")]
+		("polkadot-local", Box::new(relay_chain_specs::polkadot_local_testnet_config) as Box<_>),
+		#[cfg(feature = "kusama")]
+		("kusama-dev", Box::new(relay_chain_specs::kusama_development_config) as Box<_>),
+		#[cfg(feature = "kusama")]
+		("kusama-local", Box::new(relay_chain_specs::kusama_local_testnet_config) as Box<_>),
+		#[cfg(feature = "asset-hub-kusama")]
+		(
+			"asset-hub-kusama-local",
+			Box::new(system_parachains_specs::asset_hub_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "asset-hub-polkadot")]
+		(
+			"asset-hub-polkadot-local",
+			Box::new(system_parachains_specs::asset_hub_polkadot_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "collectives-polkadot")]
+		(
+			"collectives-polkadot-local",
+			Box::new(system_parachains_specs::collectives_polkadot_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "bridge-hub-polkadot")]
+		(
+			"bridge-hub-polkadot-local",
+			Box::new(system_parachains_specs::bridge_hub_polkadot_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "bridge-hub-kusama")]
+		(
+			"bridge-hub-kusama-local",
+			Box::new(system_parachains_specs::bridge_hub_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "glutton-kusama")]
+		(
+			"glutton-kusama-local",
+			Box::new(system_parachains_specs::glutton_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "encointer-kusama")]
+		(
+			"encointer-kusama-local",
+			Box::new(system_parachains_specs::encointer_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "coretime-kusama")]
+		("coretime-kusama", Box::new(system_parachains_specs::coretime_kusama_config) as Box<_>),
+		#[cfg(feature = "coretime-kusama")]
+		(
+			"coretime-kusama-local",
+			Box::new(system_parachains_specs::coretime_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "coretime-polkadot")]
+		(
+			"coretime-polkadot",
+			Box::new(system_parachains_specs::coretime_polkadot_config) as Box<_>,
+		),
+		#[cfg(feature = "coretime-polkadot")]
+		(
+			"coretime-polkadot-local",
+			Box::new(system_parachains_specs::coretime_polkadot_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "people-kusama")]
+		(
+			"people-kusama-local",
+			Box::new(system_parachains_specs::people_kusama_local_testnet_config) as Box<_>,
+		),
+		#[cfg(feature = "people-polkadot")]
+		(
+			"people-polkadot-local",
+			Box::new(system_parachains_specs::people_polkadot_local_testnet_config) as Box<_>,
+		),
+	])
+}
+
+/// Print the chain spec of `chain`, which is either a supported chain or a json file.
+///
+/// A json file is loaded for the given `runtime` or the runtime detected from its content. Plain
+/// json files can be converted to raw ones, raw json files can only be printed as raw. The
+/// `patches` are applied to the genesis config before the chain spec is printed.
+fn generate_chain_spec(
+	supported_chains: &SupportedChains,
+	chain: &str,
+	runtime: Option<&str>,
+	raw: bool,
+	patches: Vec<serde_json::Value>,
+) -> Result<(), String> {
+	let chain_spec = if let Some(function) = supported_chains.get(chain) {
+		(*function)()?
+	} else if chain.ends_with(".json") {
+		common::from_json_file(Path::new(chain), runtime)?
+	} else {
+		let supported = supported_chains.keys().copied().collect::<Vec<_>>().join(", ");
+		return Err(format!(
+			"Unknown chain: '{chain}', only supported: '{supported}' or a json file"
+		))
+	};
+
+	let chain_spec = if patches.is_empty() {
+		chain_spec.as_json(raw)?
+	} else {
+		let patched = patch::patch_chain_spec(&chain_spec.as_json(false)?, runtime, patches)?;
+		if raw {
+			common::from_json_bytes(patched.into_bytes(), runtime)?.as_json(true)?
 		} else {
-			Err(format!(
-				"Unknown chain: '{}', only supported: '{supported}' or a json file",
-				cli.chain,
-			))
+			patched
 		}
-	}
+	};
+	print!("{chain_spec}");
+
+	Ok(())
 }
@@ -0,0 +1,381 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	common::{self, to_hex},
+	patch, SupportedChains,
+};
+use codec::Encode;
+use sc_chain_spec::construct_genesis_block;
+use serde_json::{json, Value};
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Block as BlockT, Hash},
+	OpaqueExtrinsic, StateVersion, Storage,
+};
+use std::{fmt::Write, path::Path};
+
+/// The name of the zombienet network configuration in the output directory.
+const ZOMBIENET_CONFIG: &str = "zombienet.toml";
+
+/// The storage key of the runtime code.
+const CODE_KEY: &[u8] = b":code";
+
+/// The block type of the system parachains.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// The validators of the relay chain and the collators of every parachain.
+///
+/// These are the authorities in the `local_testnet` presets.
+const AUTHORITIES: [&str; 2] = ["alice", "bob"];
+
+/// A local relay chain network with its system parachains.
+struct Network {
+	/// The relay chain.
+	relay_chain: &'static str,
+	/// The system parachains.
+	parachains: &'static [&'static str],
+	/// The system parachains that are not connected with HRMP channels to the other system
+	/// parachains.
+	without_hrmp: &'static [&'static str],
+}
+
+const POLKADOT: Network = Network {
+	relay_chain: "polkadot-local",
+	parachains: &[
+		"asset-hub-polkadot-local",
+		"collectives-polkadot-local",
+		"bridge-hub-polkadot-local",
+		"people-polkadot-local",
+		"coretime-polkadot-local",
+	],
+	without_hrmp: &[],
+};
+
+const KUSAMA: Network = Network {
+	relay_chain: "kusama-local",
+	parachains: &[
+		"asset-hub-kusama-local",
+		"encointer-kusama-local",
+		"bridge-hub-kusama-local",
+		"people-kusama-local",
+		"coretime-kusama-local",
+		"glutton-kusama-local",
+	],
+	without_hrmp: &["glutton-kusama-local"],
+};
+
+/// A parachain of the generated network.
+struct Parachain {
+	/// The name of the chain spec.
+	name: &'static str,
+	/// The id of the parachain.
+	id: u32,
+	/// The raw chain spec.
+	chain_spec: String,
+	/// The encoded genesis header.
+	genesis_head: Vec<u8>,
+	/// The runtime code at genesis.
+	validation_code: Vec<u8>,
+}
+
+/// Generate the chain specs of the local `relay` network with all its system parachains that are
+/// compiled into this binary.
+///
+/// The parachains are registered in the genesis of the relay chain and HRMP channels are opened
+/// between all of them, except the ones in [`Network::without_hrmp`]. The raw chain specs and a
+/// zombienet network configuration referencing them by relative paths are written to `output`.
+pub fn generate_network(
+	supported_chains: &SupportedChains,
+	relay: &str,
+	output: &Path,
+) -> Result<(), String> {
+	let network = match relay {
+		"polkadot" => POLKADOT,
+		"kusama" => KUSAMA,
+		_ => return Err(format!("Unknown network: '{relay}', only supported: 'polkadot, kusama'")),
+	};
+
+	let parachains = network
+		.parachains
+		.iter()
+		.filter(|name| supported_chains.contains_key(**name))
+		.map(|name| build_parachain(supported_chains, *name, network.relay_chain))
+		.collect::<Result<Vec<_>, _>>()?;
+	let relay_chain_spec = build_relay_chain(supported_chains, &network, &parachains)?;
+
+	std::fs::create_dir_all(output)
+		.map_err(|e| format!("Failed to create '{}': {e}", output.display()))?;
+	write(&output.join(format!("{}.json", network.relay_chain)), &relay_chain_spec)?;
+	for parachain in &parachains {
+		write(&output.join(format!("{}.json", parachain.name)), &parachain.chain_spec)?;
+	}
+	write(&output.join(ZOMBIENET_CONFIG), &zombienet_config(&network, &parachains))
+}
+
+/// Build the parachain `name` and check that it belongs to `relay_chain`.
+fn build_parachain(
+	supported_chains: &SupportedChains,
+	name: &'static str,
+	relay_chain: &str,
+) -> Result<Parachain, String> {
+	let chain_spec = (supported_chains[name])()?;
+
+	let plain = serde_json::from_str::<Value>(&chain_spec.as_json(false)?)
+		.map_err(|e| format!("Failed to parse chain spec of '{name}': {e}"))?;
+	let id = plain["para_id"]
+		.as_u64()
+		.and_then(|id| u32::try_from(id).ok())
+		.ok_or_else(|| format!("Chain spec of '{name}' without valid 'para_id'"))?;
+	if plain["relay_chain"] != relay_chain {
+		return Err(format!("'{name}' is not a parachain of '{relay_chain}'"))
+	}
+
+	let storage = chain_spec.as_storage_builder().build_storage()?;
+	let validation_code = storage
+		.top
+		.get(CODE_KEY)
+		.cloned()
+		.ok_or_else(|| format!("Chain spec of '{name}' without runtime code"))?;
+	let state_version = common::runtime_version(&validation_code)
+		.ok_or_else(|| format!("Runtime code of '{name}' without runtime version"))?
+		.state_version();
+
+	Ok(Parachain {
+		name,
+		id,
+		chain_spec: chain_spec.as_json(true)?,
+		genesis_head: genesis_head(&storage, state_version),
+		validation_code,
+	})
+}
+
+/// Build the relay chain of `network` with the given `parachains` registered at genesis.
+fn build_relay_chain(
+	supported_chains: &SupportedChains,
+	network: &Network,
+	parachains: &[Parachain],
+) -> Result<String, String> {
+	let chain_spec = supported_chains
+		.get(network.relay_chain)
+		.ok_or_else(|| format!("'{}' is not compiled into this binary", network.relay_chain))?;
+	let chain_spec = chain_spec()?.as_json(false)?;
+
+	let host_config = serde_json::from_str::<Value>(&chain_spec)
+		.map_err(|e| format!("Failed to parse chain spec of '{}': {e}", network.relay_chain))?
+		.pointer("/genesis/runtimeGenesis/patch/configuration/config")
+		.cloned()
+		.ok_or("Relay chain spec without host configuration")?;
+	let host_config_value = |key: &str| {
+		host_config[key]
+			.as_u64()
+			.ok_or_else(|| format!("Host configuration without '{key}'"))
+	};
+
+	let hrmp_parachains = parachains
+		.iter()
+		.filter(|p| !network.without_hrmp.contains(&p.name))
+		.map(|p| p.id)
+		.collect::<Vec<_>>();
+	let max_capacity = host_config_value("hrmp_channel_max_capacity")?;
+	let max_message_size = host_config_value("hrmp_channel_max_message_size")?;
+	let channels = hrmp_parachains
+		.iter()
+		.flat_map(|sender| {
+			hrmp_parachains
+				.iter()
+				.filter(move |recipient| *recipient != sender)
+				.map(move |recipient| json!([sender, recipient, max_capacity, max_message_size]))
+		})
+		.collect::<Vec<_>>();
+	let max_channels = hrmp_parachains.len().saturating_sub(1) as u64;
+
+	let patch = json!({
+		"paras": {
+			"paras": parachains
+				.iter()
+				.map(|p| json!([p.id, {
+					"genesis_head": to_hex(&p.genesis_head),
+					"validation_code": to_hex(&p.validation_code),
+					"parachain": true,
+				}]))
+				.collect::<Vec<_>>(),
+		},
+		"hrmp": {
+			"preopenHrmpChannels": channels,
+		},
+		"configuration": {
+			"config": {
+				"hrmp_max_parachain_inbound_channels":
+					host_config_value("hrmp_max_parachain_inbound_channels")?.max(max_channels),
+				"hrmp_max_parachain_outbound_channels":
+					host_config_value("hrmp_max_parachain_outbound_channels")?.max(max_channels),
+			},
+		},
+	});
+
+	let patched = patch::patch_chain_spec(&chain_spec, None, vec![patch])?;
+	common::from_json_bytes(patched.into_bytes(), None)?.as_json(true)
+}
+
+/// Returns the encoded genesis header of a parachain with the given genesis `storage`.
+///
+/// This is the genesis header the collators build from the chain spec, see
+/// `cumulus_client_cli::generate_genesis_block`.
+fn genesis_head(storage: &Storage, state_version: StateVersion) -> Vec<u8> {
+	let child_roots = storage.children_default.values().map(|child| {
+		let root = BlakeTwo256::trie_root(child.data.clone().into_iter().collect(), state_version);
+		(child.child_info.prefixed_storage_key().into_inner(), root.encode())
+	});
+	let state_root = BlakeTwo256::trie_root(
+		storage.top.clone().into_iter().chain(child_roots).collect(),
+		state_version,
+	);
+
+	construct_genesis_block::<Block>(state_root, state_version).header().encode()
+}
+
+/// Returns the zombienet network configuration of `network`.
+fn zombienet_config(network: &Network, parachains: &[Parachain]) -> String {
+	let relay_chain = network.relay_chain;
+	let mut config = format!(
+		r#"[relaychain]
+default_command = "polkadot"
+chain = "{relay_chain}"
+chain_spec_path = "{relay_chain}.json"
+"#
+	);
+
+	for validator in AUTHORITIES {
+		let _ = write!(
+			config,
+			r#"
+[[relaychain.nodes]]
+name = "{validator}"
+validator = true
+"#
+		);
+	}
+
+	for Parachain { name, id, .. } in parachains {
+		// The parachains are already registered in the genesis of the relay chain.
+		let _ = write!(
+			config,
+			r#"
+[[parachains]]
+id = {id}
+chain = "{name}"
+chain_spec_path = "{name}.json"
+cumulus_based = true
+add_to_genesis = false
+register_para = false
+"#
+		);
+
+		for collator in AUTHORITIES {
+			let _ = write!(
+				config,
+				r#"
+[[parachains.collators]]
+name = "{name}-{collator}"
+command = "polkadot-parachain"
+args = ["--{collator}"]
+"#
+			);
+		}
+	}
+
+	config
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+	std::fs::write(path, content).map_err(|e| format!("Failed to write '{}': {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::hashing::{twox_128, twox_64};
+
+	fn parachain(name: &'static str, id: u32) -> Parachain {
+		Parachain {
+			name,
+			id,
+			chain_spec: String::new(),
+			genesis_head: Vec::new(),
+			validation_code: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn unknown_network_fails() {
+		assert_eq!(
+			generate_network(&SupportedChains::new(), "westend", Path::new("unused")),
+			Err("Unknown network: 'westend', only supported: 'polkadot, kusama'".into())
+		);
+	}
+
+	#[test]
+	fn zombienet_config_works() {
+		let config = zombienet_config(&POLKADOT, &[parachain("asset-hub-polkadot-local", 1000)]);
+
+		assert!(config.starts_with(
+			"[relaychain]\ndefault_command = \"polkadot\"\nchain = \"polkadot-local\"\nchain_spec_path = \"polkadot-local.json\"\n"
+		));
+		assert_eq!(config.matches("[[relaychain.nodes]]").count(), AUTHORITIES.len());
+		assert!(config.contains(
+			"\n[[parachains]]\nid = 1000\nchain = \"asset-hub-polkadot-local\"\nchain_spec_path = \"asset-hub-polkadot-local.json\"\ncumulus_based = true\nadd_to_genesis = false\nregister_para = false\n"
+		));
+		assert_eq!(config.matches("[[parachains.collators]]").count(), AUTHORITIES.len());
+		assert!(config.contains("name = \"asset-hub-polkadot-local-alice\""));
+		assert!(config.contains("name = \"asset-hub-polkadot-local-bob\""));
+	}
+
+	#[cfg(feature = "polkadot")]
+	#[test]
+	fn polkadot_network_works() {
+		let supported_chains = crate::supported_chains();
+		let output = std::env::temp_dir().join(format!("polkadot-network-{}", std::process::id()));
+		generate_network(&supported_chains, "polkadot", &output).unwrap();
+
+		let read = |name: &str| std::fs::read_to_string(output.join(name)).unwrap();
+		let relay_chain = serde_json::from_str::<Value>(&read("polkadot-local.json")).unwrap();
+		let config = read(ZOMBIENET_CONFIG);
+
+		for name in POLKADOT.parachains.iter().filter(|name| supported_chains.contains_key(**name))
+		{
+			let expected = build_parachain(&supported_chains, *name, POLKADOT.relay_chain).unwrap();
+			assert_eq!(read(&format!("{name}.json")), expected.chain_spec);
+			assert!(config.contains(&format!("id = {}\nchain = \"{name}\"\n", expected.id)));
+
+			// The parachain is registered with its genesis head at the relay chain genesis.
+			let head_key = [
+				&twox_128(b"Paras")[..],
+				&twox_128(b"Heads"),
+				&twox_64(&expected.id.encode()),
+				&expected.id.encode(),
+			]
+			.concat();
+			assert_eq!(
+				relay_chain["genesis"]["raw"]["top"][to_hex(&head_key)],
+				to_hex(&expected.genesis_head.encode())
+			);
+		}
+
+		std::fs::remove_dir_all(output).unwrap();
+	}
+}
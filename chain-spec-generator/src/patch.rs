@@ -0,0 +1,128 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+	common::{detect_runtime, GENESIS_POINTERS},
+	runtimes::find_runtime,
+};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// Apply the JSON merge `patch` to `target` as defined in RFC 7396.
+///
+/// Objects are merged recursively, `null` removes a key and every other value, including arrays,
+/// replaces the value in `target`.
+pub fn merge_patch(target: &mut Value, patch: Value) {
+	let Value::Object(patch) = patch else {
+		*target = patch;
+		return
+	};
+
+	if !target.is_object() {
+		*target = Value::Object(Map::new());
+	}
+	let target = target.as_object_mut().expect("`target` is an object; qed");
+
+	for (key, value) in patch {
+		if value.is_null() {
+			target.remove(&key);
+		} else {
+			merge_patch(target.entry(key).or_insert(Value::Null), value);
+		}
+	}
+}
+
+/// Read the merge patch from the json file at `path`.
+pub fn read_patch_file(path: &Path) -> Result<Value, String> {
+	let patch = std::fs::read(path)
+		.map_err(|e| format!("Failed to read patch file '{}': {e}", path.display()))?;
+
+	serde_json::from_slice(&patch)
+		.map_err(|e| format!("Failed to parse patch file '{}': {e}", path.display()))
+}
+
+/// Convert the `--set` argument `path=value` into a merge patch.
+///
+/// `path` is a dot separated list of keys. `value` is parsed as JSON and used as a string if it is
+/// not valid JSON.
+pub fn parse_set(arg: &str) -> Result<Value, String> {
+	let (path, value) = arg
+		.split_once('=')
+		.ok_or_else(|| format!("Invalid `--set {arg}`, expected `path=value`"))?;
+	if path.split('.').any(str::is_empty) {
+		return Err(format!("Invalid path '{path}' in `--set {arg}`"))
+	}
+
+	let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.into()));
+
+	Ok(path
+		.rsplit('.')
+		.fold(value, |value, key| Value::Object(Map::from_iter([(key.into(), value)]))))
+}
+
+/// Apply `patches` to the genesis config of the plain `chain_spec`.
+///
+/// Returns the patched chain spec after checking that the genesis config can still be
+/// deserialized into the `RuntimeGenesisConfig` of the given `runtime` or the detected runtime of
+/// `chain_spec`.
+pub fn patch_chain_spec(
+	chain_spec: &str,
+	runtime: Option<&str>,
+	patches: Vec<Value>,
+) -> Result<String, String> {
+	let mut chain_spec = serde_json::from_str::<Value>(chain_spec)
+		.map_err(|e| format!("Failed to parse chain spec: {e}"))?;
+	let runtime = match runtime {
+		Some(name) => find_runtime(name)?,
+		None => detect_runtime(&chain_spec)?,
+	};
+
+	let genesis = GENESIS_POINTERS
+		.iter()
+		.find_map(|pointer| chain_spec.pointer_mut(pointer))
+		.ok_or("Only plain chain specs can be patched")?;
+	patches.into_iter().for_each(|patch| merge_patch(genesis, patch));
+	(runtime.validate_genesis_config)(genesis)?;
+
+	serde_json::to_string_pretty(&chain_spec).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn merge_patch_works() {
+		let mut target = json!({ "a": { "b": 1, "c": [1, 2] }, "d": "x" });
+
+		merge_patch(&mut target, json!({ "a": { "b": null, "c": [3], "e": { "f": true } } }));
+
+		assert_eq!(target, json!({ "a": { "c": [3], "e": { "f": true } }, "d": "x" }));
+	}
+
+	#[test]
+	fn parse_set_works() {
+		assert_eq!(
+			parse_set("configuration.config.maxCodeSize=3145728"),
+			Ok(json!({ "configuration": { "config": { "maxCodeSize": 3145728 } } }))
+		);
+		assert_eq!(parse_set("system.name=alice"), Ok(json!({ "system": { "name": "alice" } })));
+		assert!(parse_set("system.name").is_err());
+		assert!(parse_set("system..name=1").is_err());
+	}
+}
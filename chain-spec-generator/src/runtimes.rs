@@ -0,0 +1,104 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::common::Error;
+
+/// A runtime that is compiled into the chain spec generator.
+pub struct Runtime {
+	/// The name of the runtime, e.g. `asset-hub-polkadot`.
+	pub name: &'static str,
+	/// Returns the `spec_name` of the runtime version.
+	pub spec_name: fn() -> String,
+	/// Returns the names of the genesis config presets of the runtime.
+	pub preset_names: fn() -> Vec<String>,
+	/// Returns the genesis config preset with the given name.
+	pub get_preset: fn(&str) -> Option<serde_json::Value>,
+	/// Checks that the given genesis config patch can be deserialized into the
+	/// `RuntimeGenesisConfig` of the runtime.
+	pub validate_genesis_config: fn(&serde_json::Value) -> Result<(), String>,
+}
+
+macro_rules! runtime {
+	($name:literal, $runtime:ident) => {
+		Runtime {
+			name: $name,
+			spec_name: || $runtime::VERSION.spec_name.to_string(),
+			preset_names: || {
+				$runtime::genesis_config_presets::preset_names()
+					.iter()
+					.map(|id| AsRef::<str>::as_ref(id).to_string())
+					.collect()
+			},
+			get_preset: |id| {
+				$runtime::genesis_config_presets::get_preset(&id.into())
+					.and_then(|preset| serde_json::from_slice(&preset).ok())
+			},
+			validate_genesis_config: |patch| {
+				let mut config = serde_json::to_value($runtime::RuntimeGenesisConfig::default())
+					.map_err(|e| e.to_string())?;
+				crate::patch::merge_patch(&mut config, patch.clone());
+
+				serde_json::from_value::<$runtime::RuntimeGenesisConfig>(config)
+					.map(|_| ())
+					.map_err(|e| format!("Invalid genesis config for runtime '{}': {e}", $name))
+			},
+		}
+	};
+}
+
+/// Returns all runtimes that are compiled into the chain spec generator.
+pub fn supported_runtimes() -> Vec<Runtime> {
+	vec![
+		#[cfg(feature = "polkadot")]
+		runtime!("polkadot", polkadot_runtime),
+		#[cfg(feature = "kusama")]
+		runtime!("kusama", kusama_runtime),
+		#[cfg(feature = "asset-hub-polkadot")]
+		runtime!("asset-hub-polkadot", asset_hub_polkadot_runtime),
+		#[cfg(feature = "asset-hub-kusama")]
+		runtime!("asset-hub-kusama", asset_hub_kusama_runtime),
+		#[cfg(feature = "collectives-polkadot")]
+		runtime!("collectives-polkadot", collectives_polkadot_runtime),
+		#[cfg(feature = "bridge-hub-polkadot")]
+		runtime!("bridge-hub-polkadot", bridge_hub_polkadot_runtime),
+		#[cfg(feature = "bridge-hub-kusama")]
+		runtime!("bridge-hub-kusama", bridge_hub_kusama_runtime),
+		#[cfg(feature = "encointer-kusama")]
+		runtime!("encointer-kusama", encointer_kusama_runtime),
+		#[cfg(feature = "glutton-kusama")]
+		runtime!("glutton-kusama", glutton_kusama_runtime),
+		#[cfg(feature = "coretime-kusama")]
+		runtime!("coretime-kusama", coretime_kusama_runtime),
+		#[cfg(feature = "coretime-polkadot")]
+		runtime!("coretime-polkadot", coretime_polkadot_runtime),
+		#[cfg(feature = "people-kusama")]
+		runtime!("people-kusama", people_kusama_runtime),
+		#[cfg(feature = "people-polkadot")]
+		runtime!("people-polkadot", people_polkadot_runtime),
+	]
+}
+
+/// Returns the runtime with the given `name`.
+pub fn find_runtime(name: &str) -> Result<Runtime, Error> {
+	let runtimes = supported_runtimes();
+	let supported = runtimes.iter().map(|r| r.name).collect::<Vec<_>>().join(", ");
+
+	runtimes
+		.into_iter()
+		.find(|r| r.name == name)
+		.ok_or_else(|| Error::UnsupportedRuntime { name: name.into(), supported })
+}
@@ -30,3 +30,48 @@ pub mod snowbridge {
 		pub EthLocation: Location =  Location::new(2, [GlobalConsensus(EthereumNetwork::get())]);
 	}
 }
+
+pub mod remote_proxy {
+	use frame_support::{
+		pallet_prelude::Encode, storage::storage_prefix, StorageHasher, Twox64Concat,
+	};
+	use sp_core::H256;
+	use sp_runtime::{
+		generic::Header,
+		traits::{BlakeTwo256, Header as _},
+	};
+	use sp_trie::TrieMut;
+
+	/// Build a storage proof for a relay chain state that only contains `key` with `value`.
+	///
+	/// Returns the proof and the storage root the proof is verified against.
+	pub fn prove_relay_storage(key: &[u8], value: &[u8]) -> (Vec<Vec<u8>>, H256) {
+		let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+		trie.insert(key, value).expect("Inserting into an in-memory trie works; qed");
+		drop(trie);
+
+		(db.drain().into_values().map(|d| d.0).collect(), root)
+	}
+
+	/// Build a storage proof for a relay chain state that only contains the head of the parachain
+	/// `para_id`, whose storage root is `para_storage_root`.
+	///
+	/// Returns the proof and the storage root the proof is verified against.
+	pub fn prove_para_head(para_id: u32, para_storage_root: H256) -> (Vec<Vec<u8>>, H256) {
+		let head = Header::<u32, BlakeTwo256>::new(
+			1,
+			Default::default(),
+			para_storage_root,
+			Default::default(),
+			Default::default(),
+		);
+
+		let mut key = storage_prefix(b"Paras", b"Heads").to_vec();
+		key.extend(Twox64Concat::hash(&para_id.encode()));
+
+		// `HeadData` is encoded as a plain vector of bytes.
+		prove_relay_storage(&key, &head.encode().encode())
+	}
+}
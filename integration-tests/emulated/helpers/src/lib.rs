@@ -16,8 +16,11 @@
 pub use paste;
 
 // Substrate
+pub use frame_support;
 pub use pallet_balances;
 pub use pallet_message_queue;
+pub use pallet_proxy;
+pub use pallet_remote_proxy;
 
 // Polkadot
 pub use pallet_xcm;
@@ -27,7 +30,7 @@ pub use xcm::prelude::{AccountId32, VersionedAssets, Weight, WeightLimit};
 pub use asset_test_utils;
 pub use cumulus_pallet_xcmp_queue;
 pub use emulated_integration_tests_common::{macros::Dmp, test_chain_can_claim_assets};
-pub use xcm_emulator::Chain;
+pub use xcm_emulator::{Chain, Parachain};
 
 pub mod common;
 
@@ -451,3 +454,185 @@ macro_rules! test_parachain_is_trusted_teleporter {
 		}
 	};
 }
+
+#[macro_export]
+macro_rules! test_relay_proxy_can_be_used_on_parachain {
+	( $relay:ty, $relay_runtime:ident, $para:ty, $para_runtime:ident, $amount:expr ) => {
+		$crate::paste::paste! {
+			let real = [<$relay Sender>]::get();
+			let delegate = [<$relay Receiver>]::get();
+			let receiver = [<$para Receiver>]::get();
+
+			// Add a proxy on the relay chain and read its storage entry.
+			let (key, value) = <$relay>::execute_with(|| {
+				$crate::frame_support::assert_ok!($relay_runtime::Proxy::add_proxy(
+					<$relay as $crate::Chain>::RuntimeOrigin::signed(real.clone()),
+					delegate.clone().into(),
+					Default::default(),
+					0,
+				));
+
+				let key =
+					$crate::pallet_proxy::Proxies::<$relay_runtime::Runtime>::hashed_key_for(&real);
+				let value = $crate::frame_support::storage::unhashed::get_raw(&key)
+					.expect("The proxy was just added; qed");
+
+				(key, value)
+			});
+			let (proof, storage_root) =
+				$crate::common::remote_proxy::prove_relay_storage(&key, &value);
+
+			let receiver_balance_before =
+				<$para as $crate::Chain>::account_data_of(receiver.clone()).free;
+
+			<$para>::execute_with(|| {
+				let block = 1;
+				$crate::pallet_remote_proxy::BlockToRoot::<$para_runtime::Runtime>::set(
+					$crate::frame_support::BoundedVec::truncate_from(vec![(block, storage_root)]),
+				);
+
+				let call = $para_runtime::RuntimeCall::Balances(
+					$crate::pallet_balances::Call::transfer_keep_alive {
+						dest: receiver.clone().into(),
+						value: $amount,
+					},
+				);
+
+				// Only the delegate of the relay chain proxy can use it.
+				$crate::frame_support::assert_err!(
+					$para_runtime::RemoteProxyRelayChain::remote_proxy(
+						<$para as $crate::Chain>::RuntimeOrigin::signed(receiver.clone()),
+						real.clone().into(),
+						None,
+						Box::new(call.clone()),
+						$crate::pallet_remote_proxy::RemoteProxyProof::RelayChain {
+							proof: proof.clone(),
+							block,
+						},
+					),
+					$crate::pallet_remote_proxy::Error::<$para_runtime::Runtime>::DidNotFindMatchingProxyDefinition
+				);
+
+				$crate::frame_support::assert_ok!(
+					$para_runtime::RemoteProxyRelayChain::remote_proxy(
+						<$para as $crate::Chain>::RuntimeOrigin::signed(delegate.clone()),
+						real.clone().into(),
+						None,
+						Box::new(call),
+						$crate::pallet_remote_proxy::RemoteProxyProof::RelayChain { proof, block },
+					)
+				);
+			});
+
+			let receiver_balance_after =
+				<$para as $crate::Chain>::account_data_of(receiver.clone()).free;
+			assert_eq!(receiver_balance_after, receiver_balance_before + $amount);
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! test_asset_hub_proxy_can_be_used_on_parachain {
+	( $asset_hub:ty, $asset_hub_runtime:ident, $para:ty, $para_runtime:ident, $amount:expr ) => {
+		$crate::paste::paste! {
+			let real = [<$asset_hub Sender>]::get();
+			let delegate = [<$asset_hub Receiver>]::get();
+			let receiver = [<$para Receiver>]::get();
+
+			// Add a proxy on Asset Hub and read its storage entry.
+			let (key, value) = <$asset_hub>::execute_with(|| {
+				$crate::frame_support::assert_ok!($asset_hub_runtime::Proxy::add_proxy(
+					<$asset_hub as $crate::Chain>::RuntimeOrigin::signed(real.clone()),
+					delegate.clone().into(),
+					Default::default(),
+					0,
+				));
+
+				let key = $crate::pallet_proxy::Proxies::<$asset_hub_runtime::Runtime>::hashed_key_for(&real);
+				let value = $crate::frame_support::storage::unhashed::get_raw(&key)
+					.expect("The proxy was just added; qed");
+
+				(key, value)
+			});
+			let (proof, para_storage_root) =
+				$crate::common::remote_proxy::prove_relay_storage(&key, &value);
+			let (relay_proof, storage_root) = $crate::common::remote_proxy::prove_para_head(
+				<$asset_hub as $crate::Parachain>::para_id().into(),
+				para_storage_root,
+			);
+
+			let receiver_balance_before =
+				<$para as $crate::Chain>::account_data_of(receiver.clone()).free;
+
+			<$para>::execute_with(|| {
+				let block = 1;
+				$crate::pallet_remote_proxy::BlockToRoot::<
+					$para_runtime::Runtime,
+					$para_runtime::RemoteProxyAssetHubInstance,
+				>::set($crate::frame_support::BoundedVec::truncate_from(vec![(block, storage_root)]));
+
+				let call = $para_runtime::RuntimeCall::Balances(
+					$crate::pallet_balances::Call::transfer_keep_alive {
+						dest: receiver.clone().into(),
+						value: $amount,
+					},
+				);
+
+				// Asset Hub proxies can only be proven through the head of Asset Hub.
+				$crate::frame_support::assert_err!(
+					$para_runtime::RemoteProxyAssetHub::remote_proxy(
+						<$para as $crate::Chain>::RuntimeOrigin::signed(delegate.clone()),
+						real.clone().into(),
+						None,
+						Box::new(call.clone()),
+						$crate::pallet_remote_proxy::RemoteProxyProof::RelayChain {
+							proof: proof.clone(),
+							block,
+						},
+					),
+					$crate::pallet_remote_proxy::Error::<
+						$para_runtime::Runtime,
+						$para_runtime::RemoteProxyAssetHubInstance,
+					>::UnsupportedProofKind
+				);
+
+				// Only the delegate of the Asset Hub proxy can use it.
+				$crate::frame_support::assert_err!(
+					$para_runtime::RemoteProxyAssetHub::remote_proxy(
+						<$para as $crate::Chain>::RuntimeOrigin::signed(receiver.clone()),
+						real.clone().into(),
+						None,
+						Box::new(call.clone()),
+						$crate::pallet_remote_proxy::RemoteProxyProof::Parachain {
+							relay_proof: relay_proof.clone(),
+							proof: proof.clone(),
+							block,
+						},
+					),
+					$crate::pallet_remote_proxy::Error::<
+						$para_runtime::Runtime,
+						$para_runtime::RemoteProxyAssetHubInstance,
+					>::DidNotFindMatchingProxyDefinition
+				);
+
+				$crate::frame_support::assert_ok!(
+					$para_runtime::RemoteProxyAssetHub::remote_proxy(
+						<$para as $crate::Chain>::RuntimeOrigin::signed(delegate.clone()),
+						real.clone().into(),
+						None,
+						Box::new(call),
+						$crate::pallet_remote_proxy::RemoteProxyProof::Parachain {
+							relay_proof,
+							proof,
+							block,
+						},
+					)
+				);
+			});
+
+			let receiver_balance_after =
+				<$para as $crate::Chain>::account_data_of(receiver.clone()).free;
+			assert_eq!(receiver_balance_after, receiver_balance_before + $amount);
+		}
+	};
+}
@@ -23,6 +23,7 @@ use frame_support::{
 	traits::{
 		fungible::Inspect,
 		fungibles::{Inspect as FungiblesInspect, Mutate},
+		Currency, OnUnbalanced,
 	},
 };
 use kusama_runtime::OriginCaller;
@@ -268,3 +269,78 @@ fn create_and_claim_treasury_spend_in_usdt() {
 		);
 	});
 }
+
+// Teleport the burn portion of the Treasury on the Relay Chain to an account on Asset Hub.
+#[test]
+fn treasury_burn_is_teleported_to_asset_hub() {
+	// the burn portion of the treasury.
+	let burn = 100 * GRAND;
+
+	let bob_on_asset_hub = AssetHubKusama::account_id_of(BOB);
+	let init_bob_balance = AssetHubKusama::execute_with(|| {
+		<<AssetHubKusama as AssetHubKusamaPallet>::Balances as Inspect<_>>::balance(
+			&bob_on_asset_hub,
+		)
+	});
+
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+		type Balances = <Kusama as KusamaPallet>::Balances;
+		type Treasury = <Kusama as KusamaPallet>::Treasury;
+
+		Dmp::make_parachain_reachable(1000);
+
+		let root = <Kusama as Chain>::RuntimeOrigin::root();
+
+		// Fund Treasury account on Relay Chain to pay the delivery fees.
+		assert_ok!(Balances::force_set_balance(
+			root.clone(),
+			Treasury::account_id().into(),
+			10 * GRAND,
+		));
+
+		let destination: Location = (
+			Parachain(1000),
+			Junction::AccountId32 { network: None, id: bob_on_asset_hub.clone().into() },
+		)
+			.into();
+		assert_ok!(kusama_runtime::Parameters::set_parameter(
+			root,
+			kusama_runtime::RuntimeParameters::Treasury(
+				kusama_runtime::dynamic_params::treasury::Parameters::BurnDestination(
+					kusama_runtime::dynamic_params::treasury::BurnDestination,
+					Some(kusama_runtime::TreasuryBurnDestination::Location(destination.into())),
+				)
+			),
+		));
+
+		kusama_runtime::TreasuryBurnHandler::on_unbalanced(Balances::issue(burn));
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::Deposit { value }) => {
+					value: *value == burn,
+				},
+				RuntimeEvent::XcmPallet(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+
+	AssetHubKusama::execute_with(|| {
+		type RuntimeEvent = <AssetHubKusama as Chain>::RuntimeEvent;
+		type Balances = <AssetHubKusama as AssetHubKusamaPallet>::Balances;
+
+		// Ensure that the burn portion minus the execution fees is deposited to Bob account.
+		let bob_balance = <Balances as Inspect<_>>::balance(&bob_on_asset_hub);
+		assert!(bob_balance > init_bob_balance);
+		assert!(bob_balance < init_bob_balance + burn);
+
+		assert_expected_events!(
+			AssetHubKusama,
+			vec![
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+	});
+}
@@ -17,6 +17,8 @@ mod aliases;
 mod claim_assets;
 mod fellowship_treasury;
 mod hybrid_transfers;
+mod remote_proxy;
+mod revive;
 mod reserve_transfer;
 mod send;
 mod set_xcm_versions;
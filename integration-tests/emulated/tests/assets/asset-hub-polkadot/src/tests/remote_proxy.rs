@@ -0,0 +1,28 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use integration_tests_helpers::test_relay_proxy_can_be_used_on_parachain;
+
+#[test]
+fn relay_chain_proxy_can_be_used_on_asset_hub() {
+	test_relay_proxy_can_be_used_on_parachain!(
+		Polkadot,
+		polkadot_runtime,
+		AssetHubPolkadot,
+		asset_hub_polkadot_runtime,
+		ASSET_HUB_POLKADOT_ED * 100
+	);
+}
@@ -0,0 +1,82 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use asset_hub_polkadot_runtime::Runtime as AssetHubPolkadotRuntime;
+use emulated_integration_tests_common::accounts::ALICE;
+use pallet_revive::AddressMapper;
+use polkadot_system_emulated_network::polkadot_emulated_chain::polkadot_runtime::Dmp;
+
+#[test]
+fn teleported_dot_pays_for_contract_deployment_on_asset_hub() {
+	let sender: AccountId = Polkadot::account_id_of(ALICE);
+	let deployer = AccountId::new([42u8; 32]);
+	let amount = POLKADOT_ED * 1000;
+	let value = POLKADOT_ED * 10;
+	let (code, _) = pallet_revive_fixtures::compile_module("dummy").unwrap();
+
+	Polkadot::execute_with(|| {
+		type RuntimeOrigin = <Polkadot as Chain>::RuntimeOrigin;
+
+		// Fund a new account on Asset Hub from the relay chain.
+		Dmp::make_parachain_reachable(AssetHubPolkadot::para_id());
+		let beneficiary: Location =
+			AccountId32Junction { network: None, id: deployer.clone().into() }.into();
+		assert_ok!(<Polkadot as PolkadotPallet>::XcmPallet::limited_teleport_assets(
+			RuntimeOrigin::signed(sender),
+			bx!(Polkadot::child_location_of(AssetHubPolkadot::para_id()).into()),
+			bx!(beneficiary.into()),
+			bx!(Assets::from((Here, amount)).into()),
+			0,
+			Unlimited,
+		));
+	});
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type RuntimeOrigin = <AssetHubPolkadot as Chain>::RuntimeOrigin;
+		type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+		type Revive = <AssetHubPolkadot as AssetHubPolkadotPallet>::Revive;
+		type AddressMapperOf = <AssetHubPolkadotRuntime as pallet_revive::Config>::AddressMapper;
+
+		assert!(Balances::free_balance(&deployer) > 0);
+
+		// The teleported DOT pay for deploying a contract with an endowment.
+		assert_ok!(Revive::map_account(RuntimeOrigin::signed(deployer.clone())));
+		assert_ok!(Revive::instantiate_with_code(
+			RuntimeOrigin::signed(deployer.clone()),
+			value,
+			Weight::from_parts(100_000_000_000, 1024 * 1024),
+			amount / 2,
+			code,
+			vec![],
+			None,
+		));
+
+		let deployer_address = AddressMapperOf::to_address(&deployer);
+		let contract = AssetHubPolkadot::events()
+			.into_iter()
+			.find_map(|event| match event {
+				RuntimeEvent::Revive(pallet_revive::Event::Instantiated { deployer, contract }) =>
+					(deployer == deployer_address).then_some(contract),
+				_ => None,
+			})
+			.expect("contract is instantiated");
+
+		// The contract holds the endowment.
+		let contract_account = AddressMapperOf::to_account_id(&contract);
+		assert!(Balances::free_balance(&contract_account) >= value);
+	});
+}
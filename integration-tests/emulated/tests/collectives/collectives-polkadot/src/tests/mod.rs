@@ -17,4 +17,5 @@ mod aliases;
 mod collectives_salary;
 mod fellowship;
 mod fellowship_treasury;
+mod remote_proxy;
 mod teleport;
@@ -17,4 +17,5 @@
 mod aliases;
 mod claim_assets;
 mod coretime_interface;
+mod remote_proxy;
 mod teleport;
@@ -17,4 +17,6 @@ mod aliases;
 mod claim_assets;
 mod governance;
 mod identity;
+mod recovery;
+mod remote_proxy;
 mod teleport;
@@ -0,0 +1,102 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use emulated_integration_tests_common::accounts::{ALICE, BOB, CHARLIE};
+use polkadot_runtime::{Dmp, Recovery};
+
+#[test]
+fn recovered_relay_account_can_teleport_to_people_chain() {
+	let lost: AccountId = Polkadot::account_id_of(ALICE);
+	let rescuer: AccountId = Polkadot::account_id_of(BOB);
+	let friend: AccountId = Polkadot::account_id_of(CHARLIE);
+	let amount = POLKADOT_ED * 1000;
+
+	let rescuer_balance_before = PeoplePolkadot::account_data_of(rescuer.clone()).free;
+
+	Polkadot::execute_with(|| {
+		type RuntimeOrigin = <Polkadot as Chain>::RuntimeOrigin;
+
+		// `lost` sets up a recovery configuration with a single friend and no delay.
+		assert_ok!(Recovery::create_recovery(
+			RuntimeOrigin::signed(lost.clone()),
+			vec![friend.clone()],
+			1,
+			0,
+		));
+
+		// `rescuer` recovers `lost` with the help of `friend`.
+		assert_ok!(Recovery::initiate_recovery(
+			RuntimeOrigin::signed(rescuer.clone()),
+			lost.clone().into(),
+		));
+		assert_ok!(Recovery::vouch_recovery(
+			RuntimeOrigin::signed(friend.clone()),
+			lost.clone().into(),
+			rescuer.clone().into(),
+		));
+		assert_ok!(Recovery::claim_recovery(
+			RuntimeOrigin::signed(rescuer.clone()),
+			lost.clone().into(),
+		));
+
+		// `rescuer` moves the funds of `lost` to its own account on the People chain.
+		Dmp::make_parachain_reachable(PeoplePolkadot::para_id());
+		let beneficiary: Location =
+			AccountId32Junction { network: None, id: rescuer.clone().into() }.into();
+		let call =
+			polkadot_runtime::RuntimeCall::XcmPallet(pallet_xcm::Call::limited_teleport_assets {
+				dest: bx!(Polkadot::child_location_of(PeoplePolkadot::para_id()).into()),
+				beneficiary: bx!(beneficiary.into()),
+				assets: bx!(Assets::from((Here, amount)).into()),
+				fee_asset_item: 0,
+				weight_limit: Unlimited,
+			});
+		assert_ok!(Recovery::as_recovered(
+			RuntimeOrigin::signed(rescuer.clone()),
+			lost.clone().into(),
+			bx!(call),
+		));
+
+		// Only the rescuer can act on behalf of `lost`.
+		assert!(Recovery::as_recovered(
+			RuntimeOrigin::signed(friend.clone()),
+			lost.clone().into(),
+			bx!(polkadot_runtime::RuntimeCall::Balances(pallet_balances::Call::transfer_all {
+				dest: friend.clone().into(),
+				keep_alive: false,
+			})),
+		)
+		.is_err());
+	});
+
+	PeoplePolkadot::execute_with(|| {
+		type RuntimeEvent = <PeoplePolkadot as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			PeoplePolkadot,
+			vec![
+				RuntimeEvent::Balances(pallet_balances::Event::Minted { who, .. }) => {
+					who: *who == rescuer,
+				},
+			]
+		);
+	});
+
+	let rescuer_balance_after = PeoplePolkadot::account_data_of(rescuer).free;
+	// The execution fees on the People chain are paid from the teleported amount.
+	assert!(rescuer_balance_after > rescuer_balance_before);
+	assert!(rescuer_balance_after < rescuer_balance_before + amount);
+}
@@ -0,0 +1,266 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset index pallet
+//!
+//! The pallet assigns a compact `u32` index to asset ids that are too big to be used directly, for
+//! example to derive the address of an ERC20 precompile from it. Foreign assets are identified by a
+//! `Location`, which does not fit into the four bytes an address reserves for the asset id.
+//!
+//! Indices are assigned in the order of registration starting with `0`. An index is never reused.
+//! When an asset is destroyed, its entries are removed and creating the asset again assigns it a
+//! new index. So, an address derived from an index never points to a different asset.
+//!
+//! ## Registration
+//!
+//! - [`Pallet`] implements [`AssetsCallback`]. When configured as `CallbackHandle` of a
+//!   `pallet_assets` instance, every created asset gets an index assigned.
+//!
+//! - [`migration::IndexExistingAssets`] is a multi-block migration that assigns an index to all
+//!   assets that were created before the pallet was added to the runtime.
+//!
+//! The entries are stored without a deposit. Their cost is charged as part of the asset creation,
+//! [`CallbackWeightInfo`] adds it to the weights of the `pallet_assets` instance.
+//!
+//! ## Lookup
+//!
+//! - [`Pallet::index_of`]: The index assigned to an asset id.
+//!
+//! - [`Pallet::asset_id_of`]: The asset id an index is assigned to.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod migration;
+#[cfg(test)]
+mod tests;
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+use pallet_assets::AssetsCallback;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The asset id to assign indices to.
+		type AssetId: Parameter + MaxEncodedLen;
+	}
+
+	/// The index assigned to an asset id.
+	#[pallet::storage]
+	pub type AssetIdToIndex<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32>;
+
+	/// The asset id an index is assigned to.
+	#[pallet::storage]
+	pub type IndexToAssetId<T: Config> = StorageMap<_, Twox64Concat, u32, T::AssetId>;
+
+	/// The index that will be assigned to the next registered asset id.
+	#[pallet::storage]
+	pub type NextIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// All indices are already assigned.
+		IndicesExhausted,
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Register the given `asset_id`.
+		///
+		/// Returns the index of the `asset_id`. If it doesn't have one yet, the next free index is
+		/// assigned to it.
+		pub fn register(asset_id: &T::AssetId) -> Result<u32, DispatchError> {
+			if let Some(index) = AssetIdToIndex::<T>::get(asset_id) {
+				return Ok(index)
+			}
+
+			let index = NextIndex::<T>::get();
+			let next_index = index.checked_add(1).ok_or(Error::<T>::IndicesExhausted)?;
+
+			NextIndex::<T>::put(next_index);
+			AssetIdToIndex::<T>::insert(asset_id, index);
+			IndexToAssetId::<T>::insert(index, asset_id);
+
+			Ok(index)
+		}
+
+		/// The index assigned to the given `asset_id`.
+		pub fn index_of(asset_id: &T::AssetId) -> Option<u32> {
+			AssetIdToIndex::<T>::get(asset_id)
+		}
+
+		/// The asset id the given `index` is assigned to.
+		pub fn asset_id_of(index: u32) -> Option<T::AssetId> {
+			IndexToAssetId::<T>::get(index)
+		}
+	}
+}
+
+impl<T: Config, AccountId> AssetsCallback<T::AssetId, AccountId> for Pallet<T> {
+	fn created(id: &T::AssetId, _: &AccountId) -> Result<(), ()> {
+		Self::register(id).map(|_| ()).map_err(|_| ())
+	}
+
+	fn destroyed(id: &T::AssetId) -> Result<(), ()> {
+		if let Some(index) = AssetIdToIndex::<T>::take(id) {
+			IndexToAssetId::<T>::remove(index);
+		}
+		Ok(())
+	}
+}
+
+/// The weights of the `pallet_assets` instance `W` plus the storage accesses of the asset index.
+///
+/// Must be used as `WeightInfo` of the `pallet_assets` instance that has [`Pallet`] configured as
+/// `CallbackHandle`. Weights generated from the benchmarks of such an instance include these
+/// storage accesses already and don't need the wrapper.
+pub struct CallbackWeightInfo<T, W>(PhantomData<(T, W)>);
+
+impl<T: frame_system::Config, W: pallet_assets::WeightInfo> CallbackWeightInfo<T, W> {
+	/// [`Pallet::register`]: reads `AssetIdToIndex` and `NextIndex`, writes both maps and
+	/// `NextIndex`.
+	fn created() -> Weight {
+		T::DbWeight::get().reads_writes(2, 3)
+	}
+
+	/// `AssetsCallback::destroyed`: takes the `AssetIdToIndex` entry and removes the reverse entry.
+	fn destroyed() -> Weight {
+		T::DbWeight::get().reads_writes(1, 2)
+	}
+}
+
+impl<T: frame_system::Config, W: pallet_assets::WeightInfo> pallet_assets::WeightInfo
+	for CallbackWeightInfo<T, W>
+{
+	fn create() -> Weight {
+		W::create().saturating_add(Self::created())
+	}
+	fn force_create() -> Weight {
+		W::force_create().saturating_add(Self::created())
+	}
+	fn start_destroy() -> Weight {
+		W::start_destroy()
+	}
+	fn destroy_accounts(c: u32) -> Weight {
+		W::destroy_accounts(c)
+	}
+	fn destroy_approvals(a: u32) -> Weight {
+		W::destroy_approvals(a)
+	}
+	fn finish_destroy() -> Weight {
+		W::finish_destroy().saturating_add(Self::destroyed())
+	}
+	fn mint() -> Weight {
+		W::mint()
+	}
+	fn burn() -> Weight {
+		W::burn()
+	}
+	fn transfer() -> Weight {
+		W::transfer()
+	}
+	fn transfer_keep_alive() -> Weight {
+		W::transfer_keep_alive()
+	}
+	fn force_transfer() -> Weight {
+		W::force_transfer()
+	}
+	fn freeze() -> Weight {
+		W::freeze()
+	}
+	fn thaw() -> Weight {
+		W::thaw()
+	}
+	fn freeze_asset() -> Weight {
+		W::freeze_asset()
+	}
+	fn thaw_asset() -> Weight {
+		W::thaw_asset()
+	}
+	fn transfer_ownership() -> Weight {
+		W::transfer_ownership()
+	}
+	fn set_team() -> Weight {
+		W::set_team()
+	}
+	fn set_metadata(n: u32, s: u32) -> Weight {
+		W::set_metadata(n, s)
+	}
+	fn clear_metadata() -> Weight {
+		W::clear_metadata()
+	}
+	fn force_set_metadata(n: u32, s: u32) -> Weight {
+		W::force_set_metadata(n, s)
+	}
+	fn force_clear_metadata() -> Weight {
+		W::force_clear_metadata()
+	}
+	fn force_asset_status() -> Weight {
+		W::force_asset_status()
+	}
+	fn approve_transfer() -> Weight {
+		W::approve_transfer()
+	}
+	fn transfer_approved() -> Weight {
+		W::transfer_approved()
+	}
+	fn cancel_approval() -> Weight {
+		W::cancel_approval()
+	}
+	fn force_cancel_approval() -> Weight {
+		W::force_cancel_approval()
+	}
+	fn set_min_balance() -> Weight {
+		W::set_min_balance()
+	}
+	fn touch() -> Weight {
+		W::touch()
+	}
+	fn touch_other() -> Weight {
+		W::touch_other()
+	}
+	fn refund() -> Weight {
+		W::refund()
+	}
+	fn refund_other() -> Weight {
+		W::refund_other()
+	}
+	fn block() -> Weight {
+		W::block()
+	}
+	fn transfer_all() -> Weight {
+		W::transfer_all()
+	}
+	fn total_issuance() -> Weight {
+		W::total_issuance()
+	}
+	fn balance() -> Weight {
+		W::balance()
+	}
+	fn allowance() -> Weight {
+		W::allowance()
+	}
+}
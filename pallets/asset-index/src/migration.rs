@@ -0,0 +1,107 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations of the asset index pallet.
+
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	traits::Get,
+	weights::{Weight, WeightMeter},
+};
+
+#[cfg(feature = "try-runtime")]
+use crate::AssetIdToIndex;
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const PALLET_MIGRATIONS_ID: &[u8; 22] = b"pallet-asset-index-mbm";
+
+/// Assign an index to all assets of the `pallet_assets` instance `I` that don't have one yet.
+///
+/// A multi-block migration that registers one asset per step in the iteration order of the assets
+/// storage. The cursor is the last visited asset id. Assets that are indexed already keep their
+/// index.
+pub struct IndexExistingAssets<T, I>(PhantomData<(T, I)>);
+
+impl<T, I> IndexExistingAssets<T, I>
+where
+	T: Config + pallet_assets::Config<I, AssetId = <T as Config>::AssetId>,
+	I: 'static,
+{
+	/// Reads the next asset id, its `AssetIdToIndex` entry and `NextIndex`, writes both maps and
+	/// `NextIndex`.
+	fn step_weight() -> Weight {
+		<T as frame_system::Config>::DbWeight::get().reads_writes(3, 3)
+	}
+}
+
+impl<T, I> SteppedMigration for IndexExistingAssets<T, I>
+where
+	T: Config + pallet_assets::Config<I, AssetId = <T as Config>::AssetId>,
+	I: 'static,
+{
+	type Cursor = <T as Config>::AssetId;
+	type Identifier = MigrationId<22>;
+
+	fn id() -> Self::Identifier {
+		MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = Self::step_weight();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required })
+		}
+
+		while meter.try_consume(required).is_ok() {
+			let mut iter = if let Some(last) = cursor {
+				let last_key = pallet_assets::Asset::<T, I>::hashed_key_for(last);
+				pallet_assets::Asset::<T, I>::iter_keys_from(last_key)
+			} else {
+				pallet_assets::Asset::<T, I>::iter_keys()
+			};
+
+			let Some(asset_id) = iter.next() else { return Ok(None) };
+			Pallet::<T>::register(&asset_id).map_err(|_| SteppedMigrationError::Failed)?;
+			cursor = Some(asset_id);
+		}
+
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+		frame_support::ensure!(
+			pallet_assets::Asset::<T, I>::iter_keys()
+				.all(|asset_id| AssetIdToIndex::<T>::contains_key(&asset_id)),
+			"All assets should have an index assigned"
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,220 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Asset Index Pallet
+
+use super::*;
+use crate as asset_index;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl,
+	migrations::{SteppedMigration, SteppedMigrationError},
+	traits::AsEnsureOriginWithArg,
+	weights::{constants::RocksDbWeight, WeightMeter},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use migration::IndexExistingAssets;
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchResult};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		AssetIndex: asset_index,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type DbWeight = RocksDbWeight;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Freezer = ();
+	type Holder = ();
+	type CallbackHandle = AssetIndex;
+}
+
+impl Config for Test {
+	type AssetId = u32;
+}
+
+const OWNER: u64 = 1;
+
+fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn force_create(asset_id: u32) -> DispatchResult {
+	Assets::force_create(RuntimeOrigin::root(), asset_id.into(), OWNER, true, 1)
+}
+
+fn clear_indices() {
+	let _ = AssetIdToIndex::<Test>::clear(u32::MAX, None);
+	let _ = IndexToAssetId::<Test>::clear(u32::MAX, None);
+	NextIndex::<Test>::kill();
+}
+
+#[test]
+fn created_assets_are_indexed_in_order() {
+	new_test_ext().execute_with(|| {
+		for asset_id in [42, 7, 1000] {
+			assert_ok!(force_create(asset_id));
+		}
+
+		assert_eq!(AssetIndex::index_of(&42), Some(0));
+		assert_eq!(AssetIndex::index_of(&7), Some(1));
+		assert_eq!(AssetIndex::index_of(&1000), Some(2));
+		assert_eq!(AssetIndex::index_of(&8), None);
+
+		assert_eq!(AssetIndex::asset_id_of(0), Some(42));
+		assert_eq!(AssetIndex::asset_id_of(1), Some(7));
+		assert_eq!(AssetIndex::asset_id_of(2), Some(1000));
+		assert_eq!(AssetIndex::asset_id_of(3), None);
+
+		assert_eq!(NextIndex::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn entries_are_removed_on_destroy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(force_create(42));
+		assert_ok!(force_create(7));
+
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::root(), 42.into()));
+		assert_ok!(Assets::finish_destroy(RuntimeOrigin::signed(OWNER), 42.into()));
+		assert!(pallet_assets::Asset::<Test>::get(42).is_none());
+
+		assert_eq!(AssetIndex::index_of(&42), None);
+		assert_eq!(AssetIndex::asset_id_of(0), None);
+		assert_eq!(AssetIndex::index_of(&7), Some(1));
+
+		// Creating the asset again assigns a new index, the old one is not reused.
+		assert_ok!(force_create(42));
+		assert_eq!(AssetIndex::index_of(&42), Some(2));
+		assert_eq!(AssetIndex::asset_id_of(0), None);
+		assert_eq!(NextIndex::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn register_is_idempotent() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AssetIndex::register(&42), Ok(0));
+		assert_eq!(AssetIndex::register(&42), Ok(0));
+		assert_eq!(AssetIndex::register(&7), Ok(1));
+		assert_eq!(NextIndex::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn create_fails_when_indices_are_exhausted() {
+	new_test_ext().execute_with(|| {
+		NextIndex::<Test>::put(u32::MAX);
+
+		assert_eq!(AssetIndex::register(&42), Err(Error::<Test>::IndicesExhausted.into()));
+		assert_noop!(force_create(42), pallet_assets::Error::<Test>::CallbackFailed);
+		assert_eq!(AssetIndex::index_of(&42), None);
+	});
+}
+
+/// Run the migration to completion with `steps_per_block` steps per block.
+///
+/// Returns the number of blocks it took.
+fn run_migration(steps_per_block: u64) -> u32 {
+	let step_weight = <Test as frame_system::Config>::DbWeight::get().reads_writes(3, 3);
+	let mut cursor = None;
+	let mut blocks = 0;
+	loop {
+		let mut meter = WeightMeter::with_limit(step_weight.saturating_mul(steps_per_block));
+		blocks += 1;
+		cursor = IndexExistingAssets::<Test, ()>::step(cursor, &mut meter).unwrap();
+		if cursor.is_none() {
+			return blocks
+		}
+	}
+}
+
+#[test]
+fn migration_indexes_existing_assets() {
+	new_test_ext().execute_with(|| {
+		for asset_id in [42, 7, 1000] {
+			assert_ok!(force_create(asset_id));
+		}
+		clear_indices();
+
+		// Assets that are indexed already keep their index.
+		assert_eq!(AssetIndex::register(&7), Ok(0));
+
+		assert_eq!(run_migration(1), 4);
+
+		assert_eq!(AssetIndex::index_of(&7), Some(0));
+		assert_eq!(NextIndex::<Test>::get(), 3);
+		for asset_id in [42, 7, 1000] {
+			let index = AssetIndex::index_of(&asset_id).unwrap();
+			assert_eq!(AssetIndex::asset_id_of(index), Some(asset_id));
+		}
+	});
+}
+
+#[test]
+fn migration_is_bounded_by_the_weight_limit() {
+	new_test_ext().execute_with(|| {
+		for asset_id in 0..10 {
+			assert_ok!(force_create(asset_id));
+		}
+		clear_indices();
+
+		// One step per asset and a final one that finds no asset left.
+		assert_eq!(run_migration(4), 3);
+		assert_eq!(NextIndex::<Test>::get(), 10);
+		for asset_id in 0..10 {
+			assert!(AssetIndex::index_of(&asset_id).is_some());
+		}
+
+		let mut meter = WeightMeter::with_limit(Weight::zero());
+		assert!(matches!(
+			IndexExistingAssets::<Test, ()>::step(None, &mut meter),
+			Err(SteppedMigrationError::InsufficientWeight { .. })
+		));
+	});
+}
+
+#[test]
+fn migration_without_assets_finishes_in_one_step() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(run_migration(1), 1);
+		assert_eq!(NextIndex::<Test>::get(), 0);
+	});
+}
@@ -0,0 +1,220 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! On-demand proxy pallet
+//!
+//! The pallet lets a delegate place on-demand orders on behalf of a proxied account while
+//! limiting the total amount the delegate can spend.
+//!
+//! ## Spending limits
+//!
+//! The proxied account (`real`) adds the delegate as a proxy of the
+//! [`OnDemandProxyType`](Config::OnDemandProxyType) in the proxy pallet and sets the amount the
+//! delegate can spend with [`Pallet::set_spending_limit`]. The delegate places orders with
+//! [`Pallet::place_order`]. The amount that was actually paid for an order is deducted from the
+//! spending limit. Once the limit is spent, the delegate can not place further orders until
+//! `real` sets a new limit.
+//!
+//! The limit is tracked per (`real`, `delegate`) pair. So, every delegate of an account has its
+//! own limit. Removing the proxy doesn't remove the limit, but without the proxy it can't be
+//! used anymore.
+//!
+//! The proxy type is expected to not allow any call through the proxy pallet. Otherwise the
+//! delegate could place orders without going through the spending limit.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::MaxEncodedLen;
+use frame_support::{dispatch::DispatchResult, weights::Weight, Parameter};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Places on-demand orders.
+pub trait PlaceOrder<AccountId, Balance> {
+	/// The id of the parachain an order is placed for.
+	type ParaId: Parameter + MaxEncodedLen;
+
+	/// Place an order for `para_id` paid by `who`, spending at most `max_amount`.
+	fn place_order(who: &AccountId, max_amount: Balance, para_id: Self::ParaId) -> DispatchResult;
+
+	/// The weight of [`Self::place_order`].
+	fn place_order_weight() -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::Currency};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, StaticLookup, Zero};
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+	pub(crate) type BalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+	type ParaIdOf<T> = <<T as Config>::OrderPlacer as PlaceOrder<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+	>>::ParaId;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_proxy::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Places the on-demand orders.
+		///
+		/// Orders are expected to be paid with the `Currency` of the proxy pallet.
+		type OrderPlacer: PlaceOrder<Self::AccountId, BalanceOf<Self>>;
+
+		/// The proxy type a delegate needs to place orders for the proxied account.
+		#[pallet::constant]
+		type OnDemandProxyType: Get<<Self as pallet_proxy::Config>::ProxyType>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The amount a delegate can still spend on on-demand orders of the proxied account.
+	///
+	/// Stored by the proxied account and the delegate.
+	#[pallet::storage]
+	pub type SpendingLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The spending limit of `delegate` for `real` was set.
+		SpendingLimitSet { real: T::AccountId, delegate: T::AccountId, limit: BalanceOf<T> },
+		/// The spending limit of `delegate` for `real` was removed.
+		SpendingLimitRemoved { real: T::AccountId, delegate: T::AccountId },
+		/// `delegate` placed an order for `real` and paid `spent`.
+		OrderPlaced {
+			real: T::AccountId,
+			delegate: T::AccountId,
+			spent: BalanceOf<T>,
+			remaining: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The delegate has no spending limit set by the proxied account.
+		NoSpendingLimit,
+		/// The order could cost more than the remaining spending limit.
+		SpendingLimitExceeded,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the amount `delegate` can spend on on-demand orders of the caller.
+		///
+		/// The amount replaces the remaining limit. `None` removes the limit. Setting a limit
+		/// requires `delegate` to be a proxy of the caller with
+		/// [`OnDemandProxyType`](Config::OnDemandProxyType).
+		///
+		/// Emits [`Event::SpendingLimitSet`] or [`Event::SpendingLimitRemoved`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_spending_limit())]
+		pub fn set_spending_limit(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			limit: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let real = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			match limit {
+				Some(limit) => {
+					pallet_proxy::Pallet::<T>::find_proxy(
+						&real,
+						&delegate,
+						Some(T::OnDemandProxyType::get()),
+					)?;
+					SpendingLimits::<T>::insert(&real, &delegate, limit);
+					Self::deposit_event(Event::SpendingLimitSet { real, delegate, limit });
+				},
+				None => {
+					SpendingLimits::<T>::take(&real, &delegate)
+						.ok_or(Error::<T>::NoSpendingLimit)?;
+					Self::deposit_event(Event::SpendingLimitRemoved { real, delegate });
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Place an on-demand order for `para_id` on behalf of `real`.
+		///
+		/// The caller needs to be a proxy of `real` with
+		/// [`OnDemandProxyType`](Config::OnDemandProxyType) and without delay. `max_amount` must
+		/// not exceed the remaining spending limit of the caller. The amount paid for the order is
+		/// deducted from the limit.
+		///
+		/// Emits [`Event::OrderPlaced`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::place_order()
+				.saturating_add(T::OrderPlacer::place_order_weight())
+		)]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			max_amount: BalanceOf<T>,
+			para_id: ParaIdOf<T>,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let proxy = pallet_proxy::Pallet::<T>::find_proxy(
+				&real,
+				&delegate,
+				Some(T::OnDemandProxyType::get()),
+			)?;
+			ensure!(proxy.delay.is_zero(), pallet_proxy::Error::<T>::Unannounced);
+
+			let limit =
+				SpendingLimits::<T>::get(&real, &delegate).ok_or(Error::<T>::NoSpendingLimit)?;
+			ensure!(max_amount <= limit, Error::<T>::SpendingLimitExceeded);
+
+			let balance_before = T::Currency::free_balance(&real);
+			T::OrderPlacer::place_order(&real, max_amount, para_id)?;
+			let spent = balance_before.saturating_sub(T::Currency::free_balance(&real));
+			ensure!(spent <= limit, Error::<T>::SpendingLimitExceeded);
+
+			let remaining = limit.saturating_sub(spent);
+			SpendingLimits::<T>::insert(&real, &delegate, remaining);
+			Self::deposit_event(Event::OrderPlaced { real, delegate, spent, remaining });
+
+			Ok(())
+		}
+	}
+}
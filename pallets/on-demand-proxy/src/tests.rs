@@ -0,0 +1,272 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for On-Demand Proxy Pallet
+
+use super::*;
+use crate as on_demand_proxy;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{Currency, ExistenceRequirement},
+};
+use pallet_proxy::Error as ProxyError;
+use sp_core::{ConstU32, ConstU64};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::BlakeTwo256, BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Proxy: pallet_proxy,
+		OnDemandProxy: on_demand_proxy,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type ReserveIdentifier = [u8; 8];
+	type AccountStore = System;
+}
+
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	Any,
+	OnDemandOrdering,
+}
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::OnDemandOrdering => false,
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == &ProxyType::Any || self == o
+	}
+}
+impl pallet_proxy::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU64<1>;
+	type ProxyDepositFactor = ConstU64<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type CallHasher = BlakeTwo256;
+	type MaxPending = ConstU32<2>;
+	type AnnouncementDepositBase = ConstU64<1>;
+	type AnnouncementDepositFactor = ConstU64<1>;
+	type BlockNumberProvider = System;
+}
+
+const REAL: u64 = 1;
+const DELEGATE: u64 = 2;
+const OTHER: u64 = 3;
+const POT: u64 = 99;
+const PARA: u32 = 2000;
+
+parameter_types! {
+	pub storage SpotPrice: u64 = 10;
+	pub const OnDemandOrderingProxyType: ProxyType = ProxyType::OnDemandOrdering;
+}
+
+/// Pays the current [`SpotPrice`] to [`POT`] if it doesn't exceed `max_amount`.
+pub struct MockOrderPlacer;
+impl PlaceOrder<u64, u64> for MockOrderPlacer {
+	type ParaId = u32;
+
+	fn place_order(who: &u64, max_amount: u64, _: u32) -> DispatchResult {
+		let price = SpotPrice::get();
+		if price > max_amount {
+			return Err(DispatchError::Other("spot price too high"))
+		}
+		Balances::transfer(who, &POT, price, ExistenceRequirement::KeepAlive)
+	}
+
+	fn place_order_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OrderPlacer = MockOrderPlacer;
+	type OnDemandProxyType = OnDemandOrderingProxyType;
+	type WeightInfo = ();
+}
+
+fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(REAL, 100), (DELEGATE, 10), (OTHER, 10), (POT, 1)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn add_proxy(delegate: u64, proxy_type: ProxyType, delay: u64) {
+	assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(REAL), delegate, proxy_type, delay));
+}
+
+fn set_limit(delegate: u64, limit: Option<u64>) -> DispatchResult {
+	OnDemandProxy::set_spending_limit(RuntimeOrigin::signed(REAL), delegate, limit)
+}
+
+fn place_order(delegate: u64, max_amount: u64) -> DispatchResult {
+	OnDemandProxy::place_order(RuntimeOrigin::signed(delegate), REAL, max_amount, PARA)
+}
+
+#[test]
+fn orders_are_deducted_from_the_spending_limit() {
+	new_test_ext().execute_with(|| {
+		add_proxy(DELEGATE, ProxyType::OnDemandOrdering, 0);
+		assert_ok!(set_limit(DELEGATE, Some(25)));
+		System::assert_last_event(
+			Event::SpendingLimitSet { real: REAL, delegate: DELEGATE, limit: 25 }.into(),
+		);
+
+		// The limit is reduced by the amount paid, not by `max_amount`.
+		assert_ok!(place_order(DELEGATE, 20));
+		System::assert_last_event(
+			Event::OrderPlaced { real: REAL, delegate: DELEGATE, spent: 10, remaining: 15 }.into(),
+		);
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), Some(15));
+		assert_eq!(Balances::free_balance(POT), 11);
+
+		assert_ok!(place_order(DELEGATE, 15));
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), Some(5));
+
+		// Many small orders can't exceed the limit either.
+		assert_noop!(place_order(DELEGATE, 10), Error::<Test>::SpendingLimitExceeded);
+		SpotPrice::set(&5);
+		assert_ok!(place_order(DELEGATE, 5));
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), Some(0));
+		assert_noop!(place_order(DELEGATE, 5), Error::<Test>::SpendingLimitExceeded);
+		assert_eq!(Balances::free_balance(POT), 26);
+
+		// A new limit replaces the remaining one.
+		assert_ok!(set_limit(DELEGATE, Some(5)));
+		assert_ok!(place_order(DELEGATE, 5));
+	});
+}
+
+#[test]
+fn failed_orders_keep_the_spending_limit() {
+	new_test_ext().execute_with(|| {
+		add_proxy(DELEGATE, ProxyType::OnDemandOrdering, 0);
+		assert_ok!(set_limit(DELEGATE, Some(25)));
+
+		SpotPrice::set(&20);
+		assert_noop!(place_order(DELEGATE, 15), DispatchError::Other("spot price too high"));
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), Some(25));
+	});
+}
+
+#[test]
+fn spending_limits_are_per_delegate() {
+	new_test_ext().execute_with(|| {
+		add_proxy(DELEGATE, ProxyType::OnDemandOrdering, 0);
+		add_proxy(OTHER, ProxyType::OnDemandOrdering, 0);
+		assert_ok!(set_limit(DELEGATE, Some(10)));
+
+		assert_noop!(place_order(OTHER, 10), Error::<Test>::NoSpendingLimit);
+
+		assert_ok!(set_limit(OTHER, Some(20)));
+		assert_ok!(place_order(DELEGATE, 10));
+		assert_ok!(place_order(OTHER, 10));
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), Some(0));
+		assert_eq!(SpendingLimits::<Test>::get(REAL, OTHER), Some(10));
+	});
+}
+
+#[test]
+fn orders_require_an_on_demand_proxy_without_delay() {
+	new_test_ext().execute_with(|| {
+		// No proxy at all.
+		assert_noop!(set_limit(DELEGATE, Some(10)), ProxyError::<Test>::NotProxy);
+		assert_noop!(place_order(DELEGATE, 10), ProxyError::<Test>::NotProxy);
+
+		// Other proxy types can't use the spending limit.
+		add_proxy(DELEGATE, ProxyType::Any, 0);
+		assert_noop!(set_limit(DELEGATE, Some(10)), ProxyError::<Test>::NotProxy);
+
+		// Delayed proxies would need to announce the order.
+		add_proxy(OTHER, ProxyType::OnDemandOrdering, 1);
+		assert_ok!(set_limit(OTHER, Some(10)));
+		assert_noop!(place_order(OTHER, 10), ProxyError::<Test>::Unannounced);
+
+		// Without the proxy, the limit can't be used anymore.
+		add_proxy(DELEGATE, ProxyType::OnDemandOrdering, 0);
+		assert_ok!(set_limit(DELEGATE, Some(10)));
+		assert_ok!(Proxy::remove_proxy(
+			RuntimeOrigin::signed(REAL),
+			DELEGATE,
+			ProxyType::OnDemandOrdering,
+			0
+		));
+		assert_noop!(place_order(DELEGATE, 10), ProxyError::<Test>::NotProxy);
+	});
+}
+
+#[test]
+fn spending_limit_can_be_removed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(set_limit(DELEGATE, None), Error::<Test>::NoSpendingLimit);
+
+		add_proxy(DELEGATE, ProxyType::OnDemandOrdering, 0);
+		assert_ok!(set_limit(DELEGATE, Some(10)));
+		assert_ok!(set_limit(DELEGATE, None));
+		System::assert_last_event(
+			Event::SpendingLimitRemoved { real: REAL, delegate: DELEGATE }.into(),
+		);
+
+		assert_eq!(SpendingLimits::<Test>::get(REAL, DELEGATE), None);
+		assert_noop!(place_order(DELEGATE, 10), Error::<Test>::NoSpendingLimit);
+	});
+}
@@ -0,0 +1,43 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_on_demand_proxy`.
+//!
+//! The default weights account for the storage accesses of the calls and use generous estimates
+//! for the execution time and the proof size. The weight of placing the order itself is added by
+//! [`PlaceOrder::place_order_weight`](crate::PlaceOrder::place_order_weight).
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+
+/// Weight functions needed for `pallet_on_demand_proxy`.
+pub trait WeightInfo {
+	// Reads `Proxy::Proxies` of the caller and writes the `SpendingLimits` entry.
+	fn set_spending_limit() -> Weight {
+		Weight::from_parts(30_000_000, 6_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	// Reads `Proxy::Proxies` of `real`, the `SpendingLimits` entry and the account of `real`
+	// twice, writes the `SpendingLimits` entry.
+	fn place_order() -> Weight {
+		Weight::from_parts(40_000_000, 8_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
+
+impl WeightInfo for () {}
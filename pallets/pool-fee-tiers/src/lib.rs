@@ -0,0 +1,276 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Pool fee tiers pallet
+//!
+//! The pallet lets the pools of `pallet_asset_conversion` charge different liquidity provider
+//! fees.
+//!
+//! ## Fee tiers
+//!
+//! Governance approves the fees pools can be created with by [`Pallet::set_fee_tiers`]. The
+//! [`DefaultFee`](Config::DefaultFee) is always approved. [`Pallet::create_pool`] creates a pool
+//! with one of the approved fees, pools created directly with `pallet_asset_conversion` get the
+//! default fee. Governance can change the fee of a pool with [`Pallet::set_pool_fee`].
+//!
+//! Like the `LPFee` of `pallet_asset_conversion`, fees are given in tenths of a percent, so `3`
+//! is a fee of 0.3%.
+//!
+//! ## Runtime integration
+//!
+//! `pallet_asset_conversion` has a single `LPFee` for all pools. The runtime configures
+//! [`FeeTierPoolLocator`] as its `PoolLocator` and [`PoolFee`] as its `LPFee`. The pallet looks up
+//! the pool of every hop before it calculates the amounts of the hop, also when quoting prices.
+//! The locator remembers the fee of the located pool in [`CurrentPoolFee`] and [`PoolFee`] returns
+//! it. So swaps, `quote_price_*` and the `AssetConversionApi` all use the fee of the pool.
+//!
+//! ## Migration
+//!
+//! [`migration::AssignDefaultFee`] assigns the default fee to all pools that exist when the pallet
+//! is added to a runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod migration;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use pallet_asset_conversion::PoolLocator;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use alloc::boxed::Box;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use pallet_asset_conversion::WeightInfo as _;
+
+	type AssetConversionWeightInfo<T> = <T as pallet_asset_conversion::Config>::WeightInfo;
+
+	/// The fee of pools is given in tenths of a percent and must be lower than 100%.
+	const FEE_DENOMINATOR: u32 = 1000;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_asset_conversion::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Locates the pools.
+		///
+		/// [`FeeTierPoolLocator`] wraps this locator, it must not be configured as the
+		/// `PoolLocator` of `pallet_asset_conversion` itself.
+		type InnerPoolLocator: PoolLocator<Self::AccountId, Self::AssetKind, Self::PoolId>;
+
+		/// The fee of pools without a fee tier. It is always an approved fee tier.
+		#[pallet::constant]
+		type DefaultFee: Get<u32>;
+
+		/// The maximum number of fee tiers.
+		#[pallet::constant]
+		type MaxFeeTiers: Get<u32>;
+
+		/// The origin that can change the fee tiers and the fees of pools.
+		type FeeTiersOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The fees pools can be created with, besides the [`DefaultFee`](Config::DefaultFee).
+	#[pallet::storage]
+	pub type FeeTiers<T: Config> = StorageValue<_, BoundedVec<u32, T::MaxFeeTiers>, ValueQuery>;
+
+	/// The fee of a pool.
+	///
+	/// Pools without an entry use the [`DefaultFee`](Config::DefaultFee).
+	#[pallet::storage]
+	pub type PoolFees<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, u32>;
+
+	/// The fee of the pool located last by [`FeeTierPoolLocator`].
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type CurrentPoolFee<T: Config> = StorageValue<_, u32>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The fee tiers were set.
+		FeeTiersSet { tiers: BoundedVec<u32, T::MaxFeeTiers> },
+		/// The fee of the pool `pool_id` was set.
+		PoolFeeSet { pool_id: T::PoolId, fee: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The fee is not an approved fee tier.
+		FeeNotApproved,
+		/// The fee is not lower than 100%.
+		InvalidFee,
+		/// The pool of the assets doesn't exist.
+		PoolNotFound,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			// `on_finalize` clears `CurrentPoolFee`.
+			T::DbWeight::get().writes(1)
+		}
+
+		fn on_finalize(_: BlockNumberFor<T>) {
+			CurrentPoolFee::<T>::kill();
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the fees pools can be created with.
+		///
+		/// The [`DefaultFee`](Config::DefaultFee) is approved even if it's not part of `tiers`.
+		/// Existing pools keep their fee.
+		///
+		/// Emits [`Event::FeeTiersSet`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_tiers())]
+		pub fn set_fee_tiers(
+			origin: OriginFor<T>,
+			tiers: BoundedVec<u32, T::MaxFeeTiers>,
+		) -> DispatchResult {
+			T::FeeTiersOrigin::ensure_origin(origin)?;
+			ensure!(tiers.iter().all(|fee| *fee < FEE_DENOMINATOR), Error::<T>::InvalidFee);
+
+			FeeTiers::<T>::put(&tiers);
+			Self::deposit_event(Event::FeeTiersSet { tiers });
+
+			Ok(())
+		}
+
+		/// Create a pool of `asset1` and `asset2` with an approved `fee`.
+		///
+		/// The pool is created by `pallet_asset_conversion::Pallet::create_pool` with the same
+		/// origin.
+		///
+		/// Emits [`Event::PoolFeeSet`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_pool()
+				.saturating_add(AssetConversionWeightInfo::<T>::create_pool())
+		)]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			fee: u32,
+		) -> DispatchResult {
+			ensure!(Self::is_approved(fee), Error::<T>::FeeNotApproved);
+
+			pallet_asset_conversion::Pallet::<T>::create_pool(
+				origin,
+				asset1.clone(),
+				asset2.clone(),
+			)?;
+			let pool_id = T::InnerPoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::PoolNotFound)?;
+			Self::do_set_pool_fee(pool_id, fee);
+
+			Ok(())
+		}
+
+		/// Set the fee of the pool of `asset1` and `asset2`.
+		///
+		/// The fee doesn't need to be an approved fee tier.
+		///
+		/// Emits [`Event::PoolFeeSet`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_fee())]
+		pub fn set_pool_fee(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			fee: u32,
+		) -> DispatchResult {
+			T::FeeTiersOrigin::ensure_origin(origin)?;
+			ensure!(fee < FEE_DENOMINATOR, Error::<T>::InvalidFee);
+
+			let pool_id = T::InnerPoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::PoolNotFound)?;
+			ensure!(
+				pallet_asset_conversion::Pools::<T>::contains_key(&pool_id),
+				Error::<T>::PoolNotFound
+			);
+			Self::do_set_pool_fee(pool_id, fee);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether pools can be created with `fee`.
+		pub fn is_approved(fee: u32) -> bool {
+			fee == T::DefaultFee::get() || FeeTiers::<T>::get().contains(&fee)
+		}
+
+		/// The fee of the pool `pool_id`.
+		pub fn pool_fee(pool_id: &T::PoolId) -> u32 {
+			PoolFees::<T>::get(pool_id).unwrap_or_else(T::DefaultFee::get)
+		}
+
+		fn do_set_pool_fee(pool_id: T::PoolId, fee: u32) {
+			PoolFees::<T>::insert(&pool_id, fee);
+			Self::deposit_event(Event::PoolFeeSet { pool_id, fee });
+		}
+	}
+}
+
+/// The `PoolLocator` of `pallet_asset_conversion` that remembers the fee of the located pool.
+///
+/// Locates the pools with [`InnerPoolLocator`](Config::InnerPoolLocator).
+pub struct FeeTierPoolLocator<T>(PhantomData<T>);
+impl<T: Config> PoolLocator<T::AccountId, T::AssetKind, T::PoolId> for FeeTierPoolLocator<T> {
+	fn address(id: &T::PoolId) -> Result<T::AccountId, ()> {
+		T::InnerPoolLocator::address(id)
+	}
+
+	fn pool_id(asset1: &T::AssetKind, asset2: &T::AssetKind) -> Result<T::PoolId, ()> {
+		let pool_id = T::InnerPoolLocator::pool_id(asset1, asset2)?;
+		CurrentPoolFee::<T>::put(Pallet::<T>::pool_fee(&pool_id));
+		Ok(pool_id)
+	}
+}
+
+/// The `LPFee` of `pallet_asset_conversion`.
+///
+/// The fee of the pool located last by [`FeeTierPoolLocator`], or the
+/// [`DefaultFee`](Config::DefaultFee) if no pool was located in the current block.
+pub struct PoolFee<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for PoolFee<T> {
+	fn get() -> u32 {
+		CurrentPoolFee::<T>::get().unwrap_or_else(T::DefaultFee::get)
+	}
+}
@@ -0,0 +1,98 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations of the pool fee tiers pallet.
+
+use crate::{Config, PoolFees};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	traits::Get,
+	weights::{Weight, WeightMeter},
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const PALLET_MIGRATIONS_ID: &[u8; 25] = b"pallet-pool-fee-tiers-mbm";
+
+/// Assign the [`DefaultFee`](Config::DefaultFee) to all pools of `pallet_asset_conversion` that
+/// don't have a fee yet.
+///
+/// A multi-block migration that visits one pool per step in the iteration order of the pools
+/// storage. The cursor is the last visited pool id. Pools that have a fee already keep it.
+pub struct AssignDefaultFee<T>(PhantomData<T>);
+
+impl<T: Config> AssignDefaultFee<T> {
+	/// Reads the next pool id and its `PoolFees` entry, writes the `PoolFees` entry.
+	fn step_weight() -> Weight {
+		<T as frame_system::Config>::DbWeight::get().reads_writes(2, 1)
+	}
+}
+
+impl<T: Config> SteppedMigration for AssignDefaultFee<T> {
+	type Cursor = T::PoolId;
+	type Identifier = MigrationId<25>;
+
+	fn id() -> Self::Identifier {
+		MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = Self::step_weight();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required })
+		}
+
+		while meter.try_consume(required).is_ok() {
+			let mut iter = if let Some(last) = cursor {
+				let last_key = pallet_asset_conversion::Pools::<T>::hashed_key_for(last);
+				pallet_asset_conversion::Pools::<T>::iter_keys_from(last_key)
+			} else {
+				pallet_asset_conversion::Pools::<T>::iter_keys()
+			};
+
+			let Some(pool_id) = iter.next() else { return Ok(None) };
+			if !PoolFees::<T>::contains_key(&pool_id) {
+				PoolFees::<T>::insert(&pool_id, T::DefaultFee::get());
+			}
+			cursor = Some(pool_id);
+		}
+
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+		frame_support::ensure!(
+			pallet_asset_conversion::Pools::<T>::iter_keys()
+				.all(|pool_id| PoolFees::<T>::contains_key(&pool_id)),
+			"All pools should have a fee assigned"
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,360 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Pool Fee Tiers Pallet
+
+use super::*;
+use crate as pool_fee_tiers;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl,
+	migrations::SteppedMigration,
+	parameter_types,
+	traits::{
+		fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+		AsEnsureOriginWithArg, Hooks,
+	},
+	weights::{constants::RocksDbWeight, WeightMeter},
+	BoundedVec, PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use migration::AssignDefaultFee;
+use pallet_asset_conversion::{AccountIdConverter, WithFirstAsset};
+use sp_core::{ConstU32, ConstU64};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult, Permill};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets<Instance1>,
+		PoolAssets: pallet_assets<Instance2>,
+		AssetConversion: pallet_asset_conversion,
+		PoolFeeTiers: pool_fee_tiers,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type DbWeight = RocksDbWeight;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config<pallet_assets::Instance1> for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Freezer = ();
+	type Holder = ();
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config<pallet_assets::Instance2> for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Freezer = ();
+	type Holder = ();
+}
+
+type AssetKind = NativeOrWithId<u32>;
+
+parameter_types! {
+	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+	pub const Native: AssetKind = NativeOrWithId::Native;
+	pub const LiquidityWithdrawalFee: Permill = Permill::zero();
+}
+
+type PoolIdToAccountId = AccountIdConverter<AssetConversionPalletId, (AssetKind, AssetKind)>;
+
+impl pallet_asset_conversion::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type HigherPrecisionBalance = u128;
+	type AssetKind = AssetKind;
+	type Assets = UnionOf<Balances, Assets, NativeFromLeft, AssetKind, u64>;
+	type PoolId = (AssetKind, AssetKind);
+	type PoolLocator = FeeTierPoolLocator<Test>;
+	type PoolAssetId = u32;
+	type PoolAssets = PoolAssets;
+	type PoolSetupFee = ConstU64<0>;
+	type PoolSetupFeeAsset = Native;
+	type PoolSetupFeeTarget = ();
+	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+	type LPFee = PoolFee<Test>;
+	type PalletId = AssetConversionPalletId;
+	type MaxSwapPathLength = ConstU32<3>;
+	type MintMinLiquidity = ConstU64<100>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type InnerPoolLocator = WithFirstAsset<Native, u64, AssetKind, PoolIdToAccountId>;
+	type DefaultFee = ConstU32<3>;
+	type MaxFeeTiers = ConstU32<4>;
+	type FeeTiersOrigin = EnsureRoot<u64>;
+	type WeightInfo = ();
+}
+
+const OWNER: u64 = 1;
+const USER: u64 = 2;
+const LIQUIDITY: u64 = 10_000;
+
+fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(OWNER, 100_000), (USER, 100_000)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for asset_id in [1, 2, 3] {
+			assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, OWNER, true, 1));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(OWNER), asset_id, OWNER, 100_000));
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(OWNER), asset_id, USER, 100_000));
+		}
+	});
+	ext
+}
+
+fn asset(asset_id: u32) -> Box<AssetKind> {
+	Box::new(NativeOrWithId::WithId(asset_id))
+}
+
+fn set_fee_tiers(tiers: Vec<u32>) -> DispatchResult {
+	PoolFeeTiers::set_fee_tiers(RuntimeOrigin::root(), BoundedVec::truncate_from(tiers))
+}
+
+/// Create a pool of the native asset and `asset_id` with `fee` and add liquidity to it.
+fn create_pool(asset_id: u32, fee: u32) {
+	assert_ok!(PoolFeeTiers::create_pool(
+		RuntimeOrigin::signed(OWNER),
+		Box::new(Native::get()),
+		asset(asset_id),
+		fee
+	));
+	add_liquidity(asset_id);
+}
+
+fn add_liquidity(asset_id: u32) {
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(OWNER),
+		Box::new(Native::get()),
+		asset(asset_id),
+		LIQUIDITY,
+		LIQUIDITY,
+		0,
+		0,
+		OWNER
+	));
+}
+
+/// The amount `pallet_asset_conversion` pays out for `amount_in` with `fee`.
+fn amount_out(amount_in: u64, (reserve_in, reserve_out): (u64, u64), fee: u32) -> u64 {
+	let amount_in_with_fee = amount_in as u128 * (1000 - fee as u128);
+	let numerator = amount_in_with_fee * reserve_out as u128;
+	let denominator = reserve_in as u128 * 1000 + amount_in_with_fee;
+	(numerator / denominator) as u64
+}
+
+fn reserves(asset1: &AssetKind, asset2: &AssetKind) -> (u64, u64) {
+	AssetConversion::get_reserves(asset1.clone(), asset2.clone()).unwrap()
+}
+
+#[test]
+fn quotes_use_the_fee_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(set_fee_tiers(vec![1, 10]));
+		create_pool(1, 3);
+		create_pool(2, 10);
+		assert_eq!(PoolFees::<Test>::get((Native::get(), *asset(2))), Some(10));
+
+		let native = Native::get();
+		let quote = |asset_id| {
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				native.clone(),
+				*asset(asset_id),
+				1_000,
+				true,
+			)
+			.unwrap()
+		};
+		let expected_1 = amount_out(1_000, reserves(&native, &asset(1)), 3);
+		let expected_2 = amount_out(1_000, reserves(&native, &asset(2)), 10);
+		assert!(expected_2 < expected_1);
+
+		// The fee of the quoted pool is used, independent of the pools quoted before.
+		assert_eq!(quote(2), expected_2);
+		assert_eq!(quote(1), expected_1);
+		assert_eq!(quote(2), expected_2);
+
+		// The fee of the last pool is cleared at the end of the block.
+		assert_eq!(CurrentPoolFee::<Test>::get(), Some(10));
+		PoolFeeTiers::on_finalize(1);
+		assert_eq!(CurrentPoolFee::<Test>::get(), None);
+		assert_eq!(PoolFee::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn swaps_use_the_fee_of_each_pool() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(set_fee_tiers(vec![10]));
+		create_pool(1, 3);
+		create_pool(2, 10);
+
+		let native = Native::get();
+		let amount_in = 1_000;
+		let native_out = amount_out(amount_in, reserves(&asset(1), &native), 3);
+		let expected = amount_out(native_out, reserves(&native, &asset(2)), 10);
+
+		let balance_before = Assets::balance(2, USER);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(USER),
+			vec![asset(1), Box::new(native), asset(2)],
+			amount_in,
+			1,
+			USER,
+			false
+		));
+		assert_eq!(Assets::balance(2, USER) - balance_before, expected);
+	});
+}
+
+#[test]
+fn pools_can_only_be_created_with_approved_fees() {
+	new_test_ext().execute_with(|| {
+		let create = |asset_id, fee| {
+			PoolFeeTiers::create_pool(
+				RuntimeOrigin::signed(OWNER),
+				Box::new(Native::get()),
+				asset(asset_id),
+				fee,
+			)
+		};
+		assert_noop!(create(1, 10), Error::<Test>::FeeNotApproved);
+
+		assert_noop!(
+			PoolFeeTiers::set_fee_tiers(
+				RuntimeOrigin::signed(OWNER),
+				BoundedVec::truncate_from(vec![10])
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(set_fee_tiers(vec![10, 1000]), Error::<Test>::InvalidFee);
+		assert_ok!(set_fee_tiers(vec![10]));
+		System::assert_last_event(
+			Event::FeeTiersSet { tiers: BoundedVec::truncate_from(vec![10]) }.into(),
+		);
+
+		assert_ok!(create(1, 10));
+		System::assert_last_event(
+			Event::PoolFeeSet { pool_id: (Native::get(), *asset(1)), fee: 10 }.into(),
+		);
+
+		// The default fee is always approved.
+		assert_ok!(create(2, 3));
+		assert_eq!(PoolFees::<Test>::get((Native::get(), *asset(2))), Some(3));
+
+		// Removed tiers can't be used for new pools, existing pools keep their fee.
+		assert_ok!(set_fee_tiers(vec![]));
+		assert_noop!(create(3, 10), Error::<Test>::FeeNotApproved);
+		assert_eq!(PoolFeeTiers::pool_fee(&(Native::get(), *asset(1))), 10);
+
+		// The pool can only be created once.
+		assert_noop!(create(1, 3), pallet_asset_conversion::Error::<Test>::PoolExists);
+	});
+}
+
+#[test]
+fn governance_can_change_the_fee_of_a_pool() {
+	new_test_ext().execute_with(|| {
+		let set_pool_fee = |origin, asset_id, fee| {
+			PoolFeeTiers::set_pool_fee(origin, Box::new(Native::get()), asset(asset_id), fee)
+		};
+		assert_noop!(set_pool_fee(RuntimeOrigin::root(), 1, 5), Error::<Test>::PoolNotFound);
+
+		create_pool(1, 3);
+		assert_noop!(set_pool_fee(RuntimeOrigin::signed(OWNER), 1, 5), DispatchError::BadOrigin);
+		assert_noop!(set_pool_fee(RuntimeOrigin::root(), 1, 1000), Error::<Test>::InvalidFee);
+
+		assert_ok!(set_pool_fee(RuntimeOrigin::root(), 1, 5));
+		System::assert_last_event(
+			Event::PoolFeeSet { pool_id: (Native::get(), *asset(1)), fee: 5 }.into(),
+		);
+
+		let native = Native::get();
+		let expected = amount_out(1_000, reserves(&native, &asset(1)), 5);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(native, *asset(1), 1_000, true),
+			Some(expected)
+		);
+	});
+}
+
+/// Run the migration to completion with `steps_per_block` steps per block.
+///
+/// Returns the number of blocks it took.
+fn run_migration(steps_per_block: u64) -> u32 {
+	let step_weight = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+	let mut cursor = None;
+	let mut blocks = 0;
+	loop {
+		let mut meter = WeightMeter::with_limit(step_weight.saturating_mul(steps_per_block));
+		blocks += 1;
+		cursor = AssignDefaultFee::<Test>::step(cursor, &mut meter).unwrap();
+		if cursor.is_none() {
+			return blocks
+		}
+	}
+}
+
+#[test]
+fn migration_assigns_the_default_fee_to_existing_pools() {
+	new_test_ext().execute_with(|| {
+		for asset_id in [1, 2] {
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(OWNER),
+				Box::new(Native::get()),
+				asset(asset_id)
+			));
+		}
+		assert_ok!(set_fee_tiers(vec![10]));
+		create_pool(3, 10);
+
+		assert_eq!(run_migration(1), 4);
+
+		assert_eq!(PoolFees::<Test>::get((Native::get(), *asset(1))), Some(3));
+		assert_eq!(PoolFees::<Test>::get((Native::get(), *asset(2))), Some(3));
+		// Pools that have a fee already keep it.
+		assert_eq!(PoolFees::<Test>::get((Native::get(), *asset(3))), Some(10));
+	});
+}
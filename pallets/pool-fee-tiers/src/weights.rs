@@ -0,0 +1,47 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_pool_fee_tiers`.
+//!
+//! The default weights account for the storage accesses of the calls and use generous estimates
+//! for the execution time and the proof size. The weight of creating the pool itself is added by
+//! the `WeightInfo` of `pallet_asset_conversion`.
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+
+/// Weight functions needed for `pallet_pool_fee_tiers`.
+pub trait WeightInfo {
+	// Writes `FeeTiers`.
+	fn set_fee_tiers() -> Weight {
+		Weight::from_parts(15_000_000, 1_500).saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	// Reads `FeeTiers`, writes the `PoolFees` entry.
+	fn create_pool() -> Weight {
+		Weight::from_parts(20_000_000, 3_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	// Reads the `Pools` entry of `pallet_asset_conversion`, writes the `PoolFees` entry.
+	fn set_pool_fee() -> Weight {
+		Weight::from_parts(20_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
+
+impl WeightInfo for () {}
@@ -17,33 +17,71 @@
 
 use super::*;
 use crate::Pallet as RemoteProxy;
-use alloc::{boxed::Box, vec};
-use frame_benchmarking::v2::{
-	account, impl_test_function, instance_benchmarks, whitelisted_caller,
+use alloc::{boxed::Box, vec, vec::Vec};
+use frame_benchmarking::{
+	v2::{account, impl_test_function, instance_benchmarks, whitelisted_caller},
+	BenchmarkError,
 };
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
 use sp_runtime::{
-	traits::{Bounded, StaticLookup},
+	traits::{Bounded, Hash, StaticLookup},
 	BoundedVec,
 };
 
 const SEED: u32 = 0;
 
+/// The maximum number of bytes added to the proof of `register_remote_proxy_definitions`.
+const MAX_PROOF_PADDING: u32 = 64 * 1024;
+
 type BalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+type CallHashOf<T> = <<T as pallet_proxy::Config>::CallHasher as Hash>::Output;
+
 fn assert_last_event<T: pallet_proxy::Config>(
 	generic_event: <T as pallet_proxy::Config>::RuntimeEvent,
 ) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn assert_last_remote_proxy_event<T: Config<I>, I: 'static>(event: Event<T, I>) {
+	frame_system::Pallet::<T>::assert_last_event(
+		<T as Config<I>>::RuntimeEvent::from(event).into(),
+	);
+}
+
+/// Let `delegate` announce `n` distinct calls for `real`.
+///
+/// Returns the call hash of the last announcement.
+fn add_announcements<T: Config<I>, I: 'static>(
+	delegate: &T::AccountId,
+	real: &T::AccountId,
+	proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+	n: u32,
+) -> Result<CallHashOf<T>, BenchmarkError> {
+	let mut call_hash = Default::default();
+
+	for i in 0..n {
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: i.encode() }.into();
+		call_hash = <T as pallet_proxy::Config>::CallHasher::hash_of(&call);
+
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(delegate.clone()).into(),
+			T::Lookup::unlookup(real.clone()),
+			call_hash,
+			proof.clone(),
+		)?;
+	}
+
+	Ok(call_hash)
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
-	use frame_benchmarking::BenchmarkError;
 
 	#[benchmark]
 	fn remote_proxy() -> Result<(), BenchmarkError> {
@@ -132,5 +170,241 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn announce() -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		// Worst case: only one announcement slot is left.
+		add_announcements::<T, I>(
+			&caller,
+			&real,
+			proof.clone(),
+			<T as pallet_proxy::Config>::MaxPending::get().saturating_sub(1),
+		)?;
+
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = <T as pallet_proxy::Config>::CallHasher::hash_of(&call);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash, proof);
+
+		assert_last_remote_proxy_event::<T, I>(Event::Announced { real, proxy: caller, call_hash });
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_announcement() -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		let call_hash = add_announcements::<T, I>(
+			&caller,
+			&real,
+			proof,
+			<T as pallet_proxy::Config>::MaxPending::get(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash);
+
+		let (announcements, _) = Announcements::<T, I>::get(&caller);
+		assert!(announcements.iter().all(|a| a.call_hash != call_hash));
+		assert_last_remote_proxy_event::<T, I>(Event::AnnouncementRemoved {
+			real,
+			proxy: caller,
+			call_hash,
+		});
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_announcement() -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		let call_hash = add_announcements::<T, I>(
+			&caller,
+			&real,
+			proof,
+			<T as pallet_proxy::Config>::MaxPending::get(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(real.clone()), caller_lookup, call_hash);
+
+		let (announcements, _) = Announcements::<T, I>::get(&caller);
+		assert!(announcements.iter().all(|a| a.call_hash != call_hash));
+		assert_last_remote_proxy_event::<T, I>(Event::AnnouncementRejected {
+			real,
+			proxy: caller,
+			call_hash,
+		});
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_proxy_announced() -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		add_announcements::<T, I>(
+			&caller,
+			&real,
+			proof.clone(),
+			<T as pallet_proxy::Config>::MaxPending::get().saturating_sub(1),
+		)?;
+
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		RemoteProxy::<T, I>::announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			<T as pallet_proxy::Config>::CallHasher::hash_of(&call),
+			proof.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), caller_lookup, real_lookup, None, Box::new(call), proof);
+
+		assert_last_event::<T>(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_remote_proxy_definitions(
+		n: Linear<1, { T::MaxProofAccountsPerSigner::get() }>,
+		p: Linear<0, MAX_PROOF_PADDING>,
+	) -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		// ... and the "reals" are the traditional callers. This is not a typo.
+		let reals = (0..n).map(|i| account("real", i, SEED)).collect::<Vec<T::AccountId>>();
+		let real_lookups = BoundedVec::truncate_from(
+			reals.iter().cloned().map(T::Lookup::unlookup).collect::<Vec<_>>(),
+		);
+		let (mut proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_batch_proof(&caller, &reals);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(
+			block_number.clone(),
+			storage_root,
+		)]));
+		// Unused nodes are decoded and hashed like the nodes of the proxy definitions.
+		match &mut proof {
+			RemoteProxyProof::RelayChain { proof, .. } |
+			RemoteProxyProof::Parachain { proof, .. } => proof.push(vec![0u8; p as usize]),
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookups, proof);
+
+		assert!(reals
+			.iter()
+			.all(|real| VerifiedProxyDefinitions::<T, I>::contains_key(&block_number, real)));
+		assert_eq!(VerifiedProxyDefinitionsCount::<T, I>::get(), n);
+		assert_eq!(VerifiedProxyDefinitionsBySigner::<T, I>::get(&caller), n);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_remote_proxies(
+		n: Linear<1, { T::MaxRevocations::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::RevocationOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let delegate = T::RemoteProxy::local_to_remote_account_id(&account("target", 0, SEED))
+			.ok_or(BenchmarkError::Weightless)?;
+		let revocations = (0..n)
+			.map(|i| {
+				T::RemoteProxy::local_to_remote_account_id(&account("real", i, SEED)).map(|real| {
+					RemoteProxyRevocation {
+						real,
+						delegate: delegate.clone(),
+						block: RemoteBlockNumberOf::<T, I>::from(1u32),
+					}
+				})
+			})
+			.collect::<Option<Vec<_>>>()
+			.ok_or(BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, BoundedVec::truncate_from(revocations.clone()));
+
+		assert!(revocations
+			.iter()
+			.all(|r| RevokedProxies::<T, I>::contains_key(&r.real, &r.delegate)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_expired_revocation() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		let real: T::AccountId = account("real", 0, SEED);
+		let (_, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&delegate, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(
+			block_number.clone(),
+			storage_root,
+		)]));
+
+		let delegate = T::RemoteProxy::local_to_remote_account_id(&delegate)
+			.ok_or(BenchmarkError::Weightless)?;
+		let real =
+			T::RemoteProxy::local_to_remote_account_id(&real).ok_or(BenchmarkError::Weightless)?;
+		RevokedProxies::<T, I>::insert(&real, &delegate, block_number);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), real.clone(), delegate.clone());
+
+		assert!(!RevokedProxies::<T, I>::contains_key(&real, &delegate));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(RemoteProxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
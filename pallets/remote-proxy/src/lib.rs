@@ -36,6 +36,53 @@
 //! - [`Pallet::remote_proxy_with_registered_proof`]: Use a previously registered `proof` to
 //!   dispatch the wrapped call.
 //!
+//! - [`Pallet::announce`]: Announce a call that will be dispatched by a time-delayed remote proxy.
+//!
+//! - [`Pallet::remove_announcement`]: Remove an announcement made by the caller.
+//!
+//! - [`Pallet::reject_announcement`]: Reject an announcement made for the caller.
+//!
+//! - [`Pallet::remote_proxy_announced`]: Dispatch a previously announced call using the given proof
+//!   over the existence of a time-delayed remote proxy.
+//!
+//! - [`Pallet::register_remote_proxy_definitions`]: Verify the proxy definitions of multiple
+//!   accounts using one combined proof and cache them for the current block.
+//!
+//! - [`Pallet::revoke_remote_proxies`]: Reject proofs of the given remote proxies that are older
+//!   than their revocation. Meant to be called by the remote location via XCM.
+//!
+//! - [`Pallet::remove_expired_revocation`]: Remove a revocation that is not required anymore.
+//!
+//! ## Remote parachains
+//!
+//! By default the remote location is the relay chain. If
+//! [`RemoteProxyInterface::remote_para_id`] returns a parachain, proofs need to use
+//! [`RemoteProxyProof::Parachain`]. Such a proof first proves the head of the remote parachain in
+//! the relay chain state (`Paras::Heads`) and then the proxy definition against the storage root of
+//! this head.
+//!
+//! ## Batched proofs
+//!
+//! Services that dispatch many proxied calls for different `real` accounts in the same block can
+//! prove all the required proxy definitions at once with
+//! [`Pallet::register_remote_proxy_definitions`]. The verified definitions are cached in
+//! [`VerifiedProxyDefinitions`] keyed by the anchor block of the proof and the `real` account until
+//! the start of the next block. At most [`Config::MaxProofAccounts`] accounts can be cached per
+//! block, which bounds the cleanup in `on_initialize`, and at most
+//! [`Config::MaxProofAccountsPerSigner`] of them by the same signer. Any proof for a cached `real`
+//! at the same anchor block is then not verified again, thus it is enough to pass a proof with empty `proof` nodes that only names the
+//! anchor block, for example to [`Pallet::register_remote_proxy_proof`].
+//!
+//! ## Time-delayed proxies
+//!
+//! Remote proxies with a non-zero `delay` can not be used with [`Pallet::remote_proxy`]. The
+//! delegate first needs to [`announce`](Pallet::announce) the hash of the call locally. After the
+//! `delay` has passed, the call can be dispatched with [`Pallet::remote_proxy_announced`]. The
+//! remote `delay` is converted to local blocks by
+//! [`RemoteProxyInterface::remote_to_local_delay`] and measured with the `BlockNumberProvider` of
+//! the local proxy pallet. Announcements are stored locally and require the same deposit as
+//! announcements in the local proxy pallet.
+//!
 //! ## Security considerations
 //!
 //! As explained above the security of the proxy depends on the remote location. So, if the remote
@@ -46,6 +93,25 @@
 //! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) time until the proxy can not be used
 //! anymore. The reason for this is that the caller will be able to provide an old `proof` at which
 //! the proxy was still available.
+//!
+//! There are two ways to shorten this time without reducing
+//! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep):
+//!
+//! - [`MaxProofAge`](Config::MaxProofAge) limits the age of the proof per proxy type. The age is
+//!   the number of remote blocks between the anchor block of the proof and the latest known
+//!   storage root. For example `Any` proxies can be required to prove against the latest storage
+//!   root, while `CancelProxy` proxies may use older proofs.
+//!
+//! - The remote location can push revocations via XCM to [`Pallet::revoke_remote_proxies`]. Any
+//!   proof anchored before the revocation of a proxy is rejected. A revocation applies to all
+//!   proxy types of the revoked `delegate`.
+//!
+//! ## Runtime API
+//!
+//! [`RemoteProxyApi`](runtime_api::RemoteProxyApi) helps wallets to build valid proofs. It returns
+//! the remote storage keys that need to be proven, the anchor blocks that are currently accepted
+//! and can validate a proof before it is submitted. The instance is selected by the
+//! [remote parachain](Pallet::remote_para_id) it uses.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -53,21 +119,23 @@ extern crate alloc;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
-mod weight;
+pub mod weights;
 
 use alloc::{boxed::Box, vec::Vec};
-use codec::{Encode, MaxEncodedLen};
+use codec::{Compact, Decode, Encode, MaxEncodedLen};
 use frame_support::{storage::storage_prefix, Parameter, StorageHasher, Twox64Concat};
 use scale_info::TypeInfo;
 use sp_core::Hasher;
 use sp_runtime::traits::Saturating;
 
-pub use cumulus_primitives_core::PersistedValidationData;
+pub use cumulus_primitives_core::{ParaId, PersistedValidationData};
 pub use pallet::*;
 pub use pallet_proxy::ProxyDefinition;
-pub use weight::WeightInfo;
+pub use runtime_api::RemoteProxyStorageKeys;
+pub use weights::WeightInfo;
 
 /// The remote proxy interface.
 pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
@@ -93,6 +161,35 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		validation_data: &PersistedValidationData,
 	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as Hasher>::Out)>;
 
+	/// The remote parachain.
+	///
+	/// Returns `None` if the remote location is the relay chain itself. Otherwise the proxy
+	/// definitions are proven against the state of the returned parachain, whose head is proven
+	/// against the relay chain storage roots returned by [`Self::block_to_storage_root`]. The
+	/// relay chain is expected to use the same [`Self::RemoteHasher`] as the remote parachain.
+	fn remote_para_id() -> Option<ParaId> {
+		None
+	}
+
+	/// The storage key where to find the head of the given `para_id` in the relay chain.
+	fn para_head_storage_key(para_id: ParaId) -> Vec<u8> {
+		let mut key = storage_prefix(b"Paras", b"Heads").to_vec();
+		para_id.using_encoded(|p| {
+			key.extend(Twox64Concat::hash(p));
+		});
+		key
+	}
+
+	/// Extract the storage root from the encoded header of the remote parachain.
+	///
+	/// The default implementation assumes the default header layout, which starts with the parent
+	/// hash, the compact encoded block number and the storage root.
+	fn para_head_to_storage_root(head: &[u8]) -> Option<Self::RemoteHash> {
+		let (_parent_hash, _number, storage_root) =
+			<(Self::RemoteHash, Compact<u128>, Self::RemoteHash)>::decode(&mut &head[..]).ok()?;
+		Some(storage_root)
+	}
+
 	/// The storage key where to find the [`ProxyDefinition`] for the given proxy account in the
 	/// remote chain.
 	fn proxy_definition_storage_key(proxy: &Self::RemoteAccountId) -> Vec<u8> {
@@ -108,9 +205,17 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 	/// If the conversion is not possible, return `None`.
 	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId>;
 
+	/// Convert a delay measured in remote blocks into a delay measured in local blocks.
+	///
+	/// Local blocks are the ones returned by the `BlockNumberProvider` of the local proxy pallet.
+	/// The conversion should round up, so that a delay is never shortened. If the conversion is
+	/// not possible, return `None`.
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber>;
+
 	/// Convert the remote proxy definition to the local proxy definition.
 	///
-	/// If the conversion is not possible, return `None`.
+	/// The `delay` of the returned definition is expected to be converted using
+	/// [`Self::remote_to_local_delay`]. If the conversion is not possible, return `None`.
 	fn remote_to_local_proxy_defintion(
 		remote: ProxyDefinition<
 			Self::RemoteAccountId,
@@ -128,6 +233,15 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		caller: &AccountId,
 		proxy: &AccountId,
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash);
+
+	/// Create one remote proxy proof that proves `caller` as proxy of all the given `proxies`.
+	///
+	/// Returns the same as [`Self::create_remote_proxy_proof`].
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxy_batch_proof(
+		caller: &AccountId,
+		proxies: &[AccountId],
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash);
 }
 
 #[frame_support::pallet]
@@ -135,38 +249,63 @@ pub mod pallet {
 	use super::*;
 	use cumulus_pallet_parachain_system::OnSystemEvent;
 	use cumulus_primitives_core::PersistedValidationData;
-	use frame_support::{dispatch_context, pallet_prelude::*, traits::IsSubType};
+	use frame_support::{
+		dispatch_context,
+		pallet_prelude::*,
+		traits::{Currency, IsSubType, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Dispatchable, StaticLookup, Zero};
+	use pallet_proxy::Announcement;
+	use sp_runtime::traits::{
+		BlockNumberProvider, Convert, Dispatchable, Hash, StaticLookup, Zero,
+	};
 
 	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+	type CallHashOf<T> = <<T as pallet_proxy::Config>::CallHasher as Hash>::Output;
+	type BalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// The block number used by the local proxy pallet.
+	pub(crate) type ProxyBlockNumberOf<T> =
+		<<T as pallet_proxy::Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+	type LocalProxyDefinitionOf<T> = ProxyDefinition<
+		<T as frame_system::Config>::AccountId,
+		<T as pallet_proxy::Config>::ProxyType,
+		ProxyBlockNumberOf<T>,
+	>;
+	type RemoteAnnouncementOf<T> =
+		Announcement<<T as frame_system::Config>::AccountId, CallHashOf<T>, ProxyBlockNumberOf<T>>;
 
 	pub(crate) type RemoteBlockNumberOf<T, I> =
 		<<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 			<T as frame_system::Config>::AccountId,
 			<T as pallet_proxy::Config>::ProxyType,
-			BlockNumberFor<T>,
+			ProxyBlockNumberOf<T>,
 		>>::RemoteBlockNumber;
-	type RemoteAccountIdOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
-		<T as frame_system::Config>::AccountId,
-		<T as pallet_proxy::Config>::ProxyType,
-		BlockNumberFor<T>,
-	>>::RemoteAccountId;
+	pub(crate) type RemoteAccountIdOf<T, I> =
+		<<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
+			<T as frame_system::Config>::AccountId,
+			<T as pallet_proxy::Config>::ProxyType,
+			ProxyBlockNumberOf<T>,
+		>>::RemoteAccountId;
 	type RemoteHasherOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
-		BlockNumberFor<T>,
+		ProxyBlockNumberOf<T>,
 	>>::RemoteHasher;
 	type RemoteHashOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
-		BlockNumberFor<T>,
+		ProxyBlockNumberOf<T>,
 	>>::RemoteHash;
 	type RemoteProxyTypeOf<T, I> = <<T as Config<I>>::RemoteProxy as RemoteProxyInterface<
 		<T as frame_system::Config>::AccountId,
 		<T as pallet_proxy::Config>::ProxyType,
-		BlockNumberFor<T>,
+		ProxyBlockNumberOf<T>,
 	>>::RemoteProxyType;
+	pub(crate) type RemoteProxyRevocationOf<T, I> =
+		RemoteProxyRevocation<RemoteAccountIdOf<T, I>, RemoteBlockNumberOf<T, I>>;
 	type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
 
 	#[pallet::pallet]
@@ -181,9 +320,74 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The announcements made by delegates of time-delayed remote proxies.
+	///
+	/// Maps the delegate to its pending announcements and the deposit reserved for them.
+	#[pallet::storage]
+	pub type Announcements<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(
+			BoundedVec<RemoteAnnouncementOf<T>, <T as pallet_proxy::Config>::MaxPending>,
+			BalanceOf<T>,
+		),
+		ValueQuery,
+	>;
+
+	/// The proxy definitions verified in the current block.
+	///
+	/// Maps the anchor block of the proof and the `real` account to the verified proxy definitions,
+	/// already converted to local proxy definitions. The cache is cleared at the start of the next
+	/// block.
+	#[pallet::storage]
+	pub type VerifiedProxyDefinitions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RemoteBlockNumberOf<T, I>,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<LocalProxyDefinitionOf<T>, <T as pallet_proxy::Config>::MaxProxies>,
+		OptionQuery,
+	>;
+
+	/// The number of entries in [`VerifiedProxyDefinitions`].
+	///
+	/// Bounded by [`Config::MaxProofAccounts`].
+	#[pallet::storage]
+	pub type VerifiedProxyDefinitionsCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// The number of entries in [`VerifiedProxyDefinitions`] added by each signer.
+	///
+	/// Bounded by [`Config::MaxProofAccountsPerSigner`] per signer. Cleared together with
+	/// [`VerifiedProxyDefinitions`].
+	#[pallet::storage]
+	pub type VerifiedProxyDefinitionsBySigner<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The remote proxies that were revoked at the remote location.
+	///
+	/// Maps the remote `real` account and the remote `delegate` to the remote block at which the
+	/// proxy was revoked. Proofs anchored before this block are rejected.
+	#[pallet::storage]
+	pub type RevokedProxies<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RemoteAccountIdOf<T, I>,
+		Twox64Concat,
+		RemoteAccountIdOf<T, I>,
+		RemoteBlockNumberOf<T, I>,
+		OptionQuery,
+	>;
+
 	/// Configuration trait.
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_proxy::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The maximum number of storage roots to keep.
 		///
 		/// The storage roots are used to validate the remote proofs. The more we keep in storage,
@@ -193,17 +397,69 @@ pub mod pallet {
 		/// the latest added storage root will be available for validating proofs.
 		type MaxStorageRootsToKeep: Get<u32>;
 
+		/// The maximum number of accounts whose proxy definitions can be verified and cached per
+		/// block by [`Pallet::register_remote_proxy_definitions`].
+		#[pallet::constant]
+		type MaxProofAccounts: Get<u32>;
+
+		/// The maximum number of accounts whose proxy definitions one signer can verify and cache
+		/// per block by [`Pallet::register_remote_proxy_definitions`].
+		///
+		/// Should be lower than [`Config::MaxProofAccounts`], so one signer can not use up the
+		/// cache of the block for everyone else.
+		#[pallet::constant]
+		type MaxProofAccountsPerSigner: Get<u32>;
+
+		/// The maximum age of a proof per proxy type.
+		///
+		/// The age is the number of remote blocks between the anchor block of the proof and the
+		/// latest known storage root. `0` means that only proofs against the latest storage root
+		/// are accepted for the given proxy type. The age is always limited by
+		/// [`Config::MaxStorageRootsToKeep`].
+		type MaxProofAge: Convert<Self::ProxyType, u32>;
+
+		/// The origin that can revoke remote proxies.
+		///
+		/// This is expected to be the remote location, pushing revocations via XCM.
+		type RevocationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of revocations that can be pushed at once.
+		#[pallet::constant]
+		type MaxRevocations: Get<u32>;
+
 		/// The interface for interacting with the remote proxy.
+		///
+		/// The local block number is the one used by the local proxy pallet, as remote proxy
+		/// delays are compared against it.
 		type RemoteProxy: RemoteProxyInterface<
 			Self::AccountId,
 			Self::ProxyType,
-			BlockNumberFor<Self>,
+			ProxyBlockNumberOf<Self>,
 		>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			let count = VerifiedProxyDefinitionsCount::<T, I>::get();
+			if count == 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			// The count is bounded by `MaxProofAccounts`, so this removes all cached definitions.
+			// Every signer added at least one of them, so the signers are removed as well.
+			let _ = VerifiedProxyDefinitions::<T, I>::clear(count, None);
+			let signers = VerifiedProxyDefinitionsBySigner::<T, I>::clear(count, None).unique;
+			VerifiedProxyDefinitionsCount::<T, I>::kill();
+
+			T::DbWeight::get()
+				.reads_writes(1, count.saturating_add(signers).saturating_add(1).into())
+		}
+	}
+
 	impl<T: Config<I>, I: 'static> OnSystemEvent for Pallet<T, I> {
 		fn on_validation_data(validation_data: &PersistedValidationData) {
 			let Some((block, hash)) = T::RemoteProxy::block_to_storage_root(validation_data) else {
@@ -229,6 +485,17 @@ pub mod pallet {
 		fn on_validation_code_applied() {}
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A remote proxy announced a call that will be dispatched after its delay.
+		Announced { real: T::AccountId, proxy: T::AccountId, call_hash: CallHashOf<T> },
+		/// A remote proxy removed one of its announcements.
+		AnnouncementRemoved { real: T::AccountId, proxy: T::AccountId, call_hash: CallHashOf<T> },
+		/// The real account rejected an announcement of one of its remote proxies.
+		AnnouncementRejected { real: T::AccountId, proxy: T::AccountId, call_hash: CallHashOf<T> },
+	}
+
 	#[pallet::error]
 	#[derive(PartialEq)]
 	pub enum Error<T, I = ()> {
@@ -246,6 +513,28 @@ pub mod pallet {
 		DidNotFindMatchingProxyDefinition,
 		/// Proxy proof not registered.
 		ProxyProofNotRegistered,
+		/// The kind of the proof does not match the remote location.
+		UnsupportedProofKind,
+		/// Failed to decode the head of the remote parachain from the proof.
+		ParaHeadDecodingFailed,
+		/// There are too many pending announcements.
+		TooManyAnnouncements,
+		/// The announcement could not be found.
+		AnnouncementNotFound,
+		/// The remote account has more proxy definitions than can be cached locally.
+		TooManyProxyDefinitions,
+		/// The proof is too old for the proxy type.
+		ProofTooOld,
+		/// The proxy definitions of too many accounts were verified in the current block.
+		TooManyVerifiedProxyDefinitions,
+		/// The proxy was revoked after the anchor block of the proof.
+		ProxyRevoked,
+		/// The revocation could not be found.
+		RevocationNotFound,
+		/// The revocation is still required, as proofs before it can still be verified.
+		RevocationStillRequired,
+		/// The signer verified the proxy definitions of too many accounts in the current block.
+		TooManyVerifiedProxyDefinitionsBySigner,
 	}
 
 	/// The remote proxy proof to prove the existence of a proxy account.
@@ -255,6 +544,41 @@ pub mod pallet {
 	pub enum RemoteProxyProof<RemoteBlockNumber> {
 		/// Assumes the default proxy storage layout.
 		RelayChain { proof: Vec<Vec<u8>>, block: RemoteBlockNumber },
+		/// Assumes the default proxy and `Paras` storage layout.
+		///
+		/// The `relay_proof` proves the head of the remote parachain in the relay chain state at
+		/// `block`. The `proof` proves the proxy definition in the state of the remote parachain.
+		Parachain { relay_proof: Vec<Vec<u8>>, proof: Vec<Vec<u8>>, block: RemoteBlockNumber },
+	}
+
+	impl<RemoteBlockNumber> RemoteProxyProof<RemoteBlockNumber> {
+		/// The anchor block of the proof.
+		pub fn block(&self) -> &RemoteBlockNumber {
+			match self {
+				Self::RelayChain { block, .. } | Self::Parachain { block, .. } => block,
+			}
+		}
+	}
+
+	/// A proxy revocation pushed by the remote location.
+	#[derive(
+		core::fmt::Debug,
+		Clone,
+		Decode,
+		DecodeWithMemTracking,
+		Encode,
+		TypeInfo,
+		MaxEncodedLen,
+		PartialEq,
+		Eq,
+	)]
+	pub struct RemoteProxyRevocation<RemoteAccountId, RemoteBlockNumber> {
+		/// The account that revoked the proxy.
+		pub real: RemoteAccountId,
+		/// The revoked proxy account.
+		pub delegate: RemoteAccountId,
+		/// The remote block at which the proxy was revoked.
+		pub block: RemoteBlockNumber,
 	}
 
 	/// The dispatch context to keep track of registered proofs.
@@ -352,6 +676,10 @@ pub mod pallet {
 		/// [`Self::remote_proxy`] is that the proof nees to registered before using
 		/// [`Self::register_remote_proxy_proof`] (see for more information).
 		///
+		/// If the proxy definitions of `real` were cached in the current block by
+		/// [`Self::register_remote_proxy_definitions`], the registered proof is not verified again
+		/// and only needs to name the anchor block.
+		///
 		/// Parameters:
 		/// - `real`: The account that the proxy will make a call on behalf of.
 		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
@@ -383,6 +711,296 @@ pub mod pallet {
 
 			Self::do_remote_proxy(who, real, force_proxy_type, *call, proof)
 		}
+
+		/// Publish the hash of a call that will be dispatched by a time-delayed remote proxy.
+		///
+		/// This must be called at least the (converted) `delay` of the remote proxy definition
+		/// before the call is dispatched using [`Self::remote_proxy_announced`].
+		///
+		/// No more than [`MaxPending`](pallet_proxy::Config::MaxPending) announcements may be made
+		/// at any one time. The caller reserves a deposit calculated from
+		/// [`AnnouncementDepositBase`](pallet_proxy::Config::AnnouncementDepositBase) and
+		/// [`AnnouncementDepositFactor`](pallet_proxy::Config::AnnouncementDepositFactor) per
+		/// announcement.
+		///
+		/// The dispatch origin for this call must be _Signed_ and a remote proxy of `real`.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call_hash`: The hash of the call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(3)]
+		#[pallet::weight({(WeightInfoOf::<T, I>::announce(), DispatchClass::Normal)})]
+		pub fn announce(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::find_remote_proxy_definition(&who, &real, None, proof)?;
+
+			let announcement = Announcement {
+				real: real.clone(),
+				call_hash,
+				height: <T as pallet_proxy::Config>::BlockNumberProvider::current_block_number(),
+			};
+
+			Announcements::<T, I>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
+				pending
+					.try_push(announcement)
+					.map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
+				*deposit = Self::rejig_announcement_deposit(&who, *deposit, pending.len())?;
+				Ok::<(), DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::Announced { real, proxy: who, call_hash });
+
+			Ok(())
+		}
+
+		/// Remove a given announcement.
+		///
+		/// May be called by a remote proxy account to remove a call they previously announced and
+		/// return the deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy announced to make a call on behalf of.
+		/// - `call_hash`: The hash of the call that was announced.
+		#[pallet::call_index(4)]
+		#[pallet::weight({(WeightInfoOf::<T, I>::remove_announcement(), DispatchClass::Normal)})]
+		pub fn remove_announcement(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::edit_announcements(&who, |ann| ann.real != real || ann.call_hash != call_hash)?;
+
+			Self::deposit_event(Event::AnnouncementRemoved { real, proxy: who, call_hash });
+
+			Ok(())
+		}
+
+		/// Remove the given announcement of a delegate.
+		///
+		/// May be called by a target (real) account to remove a call that one of their remote
+		/// proxies has announced they want to execute. The deposit is returned to the delegate.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that previously announced the call.
+		/// - `call_hash`: The hash of the call to be made.
+		#[pallet::call_index(5)]
+		#[pallet::weight({(WeightInfoOf::<T, I>::reject_announcement(), DispatchClass::Normal)})]
+		pub fn reject_announcement(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::edit_announcements(&delegate, |ann| {
+				ann.real != who || ann.call_hash != call_hash
+			})?;
+
+			Self::deposit_event(Event::AnnouncementRejected {
+				real: who,
+				proxy: delegate,
+				call_hash,
+			});
+
+			Ok(())
+		}
+
+		/// Dispatch the given `call` that was previously announced by `delegate`, a time-delayed
+		/// remote proxy of `real`.
+		///
+		/// The announcement is removed and its deposit returned to `delegate`, if the call was
+		/// announced at least the (converted) `delay` of the remote proxy definition ago.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that previously announced the call.
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
+		/// - `call`: The call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(WeightInfoOf::<T, I>::remote_proxy_announced()
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn remote_proxy_announced(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			real: AccountIdLookupOf<T>,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let def =
+				Self::find_remote_proxy_definition(&delegate, &real, force_proxy_type, proof)?;
+
+			let call_hash = <T as pallet_proxy::Config>::CallHasher::hash_of(&call);
+			let now = <T as pallet_proxy::Config>::BlockNumberProvider::current_block_number();
+			Self::edit_announcements(&delegate, |ann| {
+				ann.real != real ||
+					ann.call_hash != call_hash ||
+					now.saturating_sub(ann.height) < def.delay
+			})
+			.map_err(|_| Error::<T, I>::Unannounced)?;
+
+			Self::do_proxy(def, real, *call);
+
+			Ok(())
+		}
+
+		/// Verify the proxy definitions of all `reals` using one combined `proof` and cache them
+		/// for the rest of the current block.
+		///
+		/// At most [`Config::MaxProofAccounts`] accounts can be cached per block and at most
+		/// [`Config::MaxProofAccountsPerSigner`] of them by the same signer.
+		///
+		/// The `proof` needs to cover the proxy definitions of every account in `reals`. Later
+		/// calls in the same block that use a proof with the same anchor block for one of the
+		/// `reals` skip the verification of the proof. See [`VerifiedProxyDefinitions`] for more
+		/// information.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `reals`: The accounts whose proxy definitions should be verified.
+		/// - `proof`: The proof from the remote chain about the proxy definitions of `reals`.
+		#[pallet::call_index(7)]
+		#[pallet::weight({
+			(WeightInfoOf::<T, I>::register_remote_proxy_definitions(
+				reals.len() as u32,
+				proof.encoded_size() as u32,
+			),
+			DispatchClass::Normal)
+		})]
+		pub fn register_remote_proxy_definitions(
+			origin: OriginFor<T>,
+			reals: BoundedVec<AccountIdLookupOf<T>, T::MaxProofAccounts>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let block = proof.block().clone();
+			let (storage_root, proof) = Self::remote_storage_root(proof)?;
+			let db = sp_trie::StorageProof::new(proof).into_memory_db::<RemoteHasherOf<T, I>>();
+
+			let mut count = VerifiedProxyDefinitionsCount::<T, I>::get();
+			let mut signer_count = VerifiedProxyDefinitionsBySigner::<T, I>::get(&who);
+			for real in reals {
+				let real = T::Lookup::lookup(real)?;
+				let proxy_definitions = Self::read_proxy_definitions(&db, &storage_root, &real)?;
+				let proxy_definitions = BoundedVec::try_from(proxy_definitions)
+					.map_err(|_| Error::<T, I>::TooManyProxyDefinitions)?;
+
+				if !VerifiedProxyDefinitions::<T, I>::contains_key(&block, &real) {
+					count.saturating_inc();
+					ensure!(
+						count <= T::MaxProofAccounts::get(),
+						Error::<T, I>::TooManyVerifiedProxyDefinitions
+					);
+					signer_count.saturating_inc();
+					ensure!(
+						signer_count <= T::MaxProofAccountsPerSigner::get(),
+						Error::<T, I>::TooManyVerifiedProxyDefinitionsBySigner
+					);
+				}
+
+				VerifiedProxyDefinitions::<T, I>::insert(&block, &real, proxy_definitions);
+			}
+			VerifiedProxyDefinitionsCount::<T, I>::put(count);
+			if signer_count > 0 {
+				VerifiedProxyDefinitionsBySigner::<T, I>::insert(&who, signer_count);
+			}
+
+			Ok(())
+		}
+
+		/// Revoke the given remote proxies.
+		///
+		/// Proofs of a revoked proxy that are anchored before the block of the revocation are
+		/// rejected. If there is already a revocation for the same `real` and `delegate`, the
+		/// latest one is kept.
+		///
+		/// The dispatch origin for this call must be [`Config::RevocationOrigin`].
+		///
+		/// Parameters:
+		/// - `revocations`: The revoked remote proxies.
+		#[pallet::call_index(8)]
+		#[pallet::weight({(
+			WeightInfoOf::<T, I>::revoke_remote_proxies(revocations.len() as u32),
+			DispatchClass::Normal,
+		)})]
+		pub fn revoke_remote_proxies(
+			origin: OriginFor<T>,
+			revocations: BoundedVec<RemoteProxyRevocationOf<T, I>, T::MaxRevocations>,
+		) -> DispatchResult {
+			T::RevocationOrigin::ensure_origin(origin)?;
+
+			for RemoteProxyRevocation { real, delegate, block } in revocations {
+				RevokedProxies::<T, I>::mutate(&real, &delegate, |revoked_at| {
+					if revoked_at.as_ref().is_none_or(|r| *r < block) {
+						*revoked_at = Some(block);
+					}
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Remove the revocation of `delegate` for `real`.
+		///
+		/// A revocation is not required anymore when there is no stored storage root before the
+		/// block of the revocation, as proofs anchored before it can not be verified anymore.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `real`: The remote account that revoked the proxy.
+		/// - `delegate`: The revoked remote proxy account.
+		#[pallet::call_index(9)]
+		#[pallet::weight({(WeightInfoOf::<T, I>::remove_expired_revocation(), DispatchClass::Normal)})]
+		pub fn remove_expired_revocation(
+			origin: OriginFor<T>,
+			real: RemoteAccountIdOf<T, I>,
+			delegate: RemoteAccountIdOf<T, I>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let revoked_at = RevokedProxies::<T, I>::get(&real, &delegate)
+				.ok_or(Error::<T, I>::RevocationNotFound)?;
+			ensure!(
+				BlockToRoot::<T, I>::get().first().is_some_and(|(b, _)| *b >= revoked_at),
+				Error::<T, I>::RevocationStillRequired
+			);
+
+			RevokedProxies::<T, I>::remove(&real, &delegate);
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -393,73 +1011,306 @@ pub mod pallet {
 			call: <T as pallet_proxy::Config>::RuntimeCall,
 			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
 		) -> DispatchResult {
-			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(&real) else {
-				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
-			};
+			let def = Self::find_remote_proxy_definition(&who, &real, force_proxy_type, proof)?;
 
-			let def = match proof {
-				RemoteProxyProof::RelayChain { proof, block } => {
-					let roots = BlockToRoot::<T, I>::get();
+			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+
+			Self::do_proxy(def, real, call);
+
+			Ok(())
+		}
+
+		/// The remote parachain of this instance.
+		///
+		/// Returns `None` if the remote location is the relay chain.
+		pub fn remote_para_id() -> Option<ParaId> {
+			T::RemoteProxy::remote_para_id()
+		}
+
+		/// Returns the remote storage keys that need to be proven to use a remote proxy of
+		/// `real`.
+		///
+		/// Returns `None` if `real` can not be converted to a remote account.
+		pub fn remote_storage_keys(real: &T::AccountId) -> Option<RemoteProxyStorageKeys> {
+			let real_remote = T::RemoteProxy::local_to_remote_account_id(real)?;
+
+			Some(RemoteProxyStorageKeys {
+				proxy_definitions: T::RemoteProxy::proxy_definition_storage_key(&real_remote),
+				para_head: T::RemoteProxy::remote_para_id()
+					.map(T::RemoteProxy::para_head_storage_key),
+			})
+		}
+
+		/// Returns the anchor blocks that are currently accepted for proofs.
+		///
+		/// If `proxy_type` is given, only the anchor blocks that are recent enough for proxies of
+		/// this type are returned (see [`Config::MaxProofAge`]).
+		pub fn acceptable_anchor_blocks(
+			proxy_type: Option<T::ProxyType>,
+		) -> Vec<RemoteBlockNumberOf<T, I>> {
+			let roots = BlockToRoot::<T, I>::get();
+			let latest_block = roots.last().map(|(b, _)| b.clone()).unwrap_or_default();
+			let max_age = proxy_type.map(T::MaxProofAge::convert);
+
+			roots
+				.into_iter()
+				.map(|(b, _)| b)
+				.filter(|b| {
+					max_age.is_none_or(|max_age| {
+						latest_block.clone().saturating_sub(b.clone()) <= max_age.into()
+					})
+				})
+				.collect()
+		}
+
+		/// Validate the given remote `proof` about a proxy of `delegate` for `real`.
+		///
+		/// Returns all proxy definitions of `delegate` for `real` that can be used with `proof`.
+		/// Definitions with a non-zero `delay` are included, they require an announcement.
+		pub fn validate_remote_proxy_proof(
+			real: &T::AccountId,
+			delegate: &T::AccountId,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<Vec<LocalProxyDefinitionOf<T>>, DispatchError> {
+			Self::matching_remote_proxy_definitions(delegate, real, None, proof)
+		}
 
-					let Ok(storage_root) = roots
-						.binary_search_by(|(b, _)| b.cmp(&block))
-						.map(|pos| roots[pos].1.clone())
-					else {
-						return Err(Error::<T, I>::UnknownProofAnchorBlock.into());
-					};
+		/// Find the proxy definition of `delegate` for `real` in the given remote `proof`.
+		///
+		/// See [`Self::matching_remote_proxy_definitions`] for the details.
+		fn find_remote_proxy_definition(
+			delegate: &T::AccountId,
+			real: &T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<LocalProxyDefinitionOf<T>, DispatchError> {
+			Self::matching_remote_proxy_definitions(delegate, real, force_proxy_type, proof)?
+				.into_iter()
+				.next()
+				.ok_or(Error::<T, I>::DidNotFindMatchingProxyDefinition.into())
+		}
 
-					let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
+		/// Returns all proxy definitions of `delegate` for `real` in the given remote `proof`
+		/// that can be used.
+		///
+		/// The `proof` is not verified if the proxy definitions of `real` at the anchor block of
+		/// the `proof` are found in [`VerifiedProxyDefinitions`]. A proxy definition is only
+		/// returned if the `proof` is recent enough for its proxy type (see
+		/// [`Config::MaxProofAge`]) and the proxy was not revoked after the anchor block.
+		fn matching_remote_proxy_definitions(
+			delegate: &T::AccountId,
+			real: &T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<Vec<LocalProxyDefinitionOf<T>>, DispatchError> {
+			let anchor_block = proof.block().clone();
+			Self::ensure_not_revoked(delegate, real, &anchor_block)?;
 
+			let cached = VerifiedProxyDefinitions::<T, I>::get(&anchor_block, real);
+			let proxy_definitions = match cached {
+				Some(proxy_definitions) => proxy_definitions.into_inner(),
+				None => {
+					let (storage_root, proof) = Self::remote_storage_root(proof)?;
 					let db =
 						sp_trie::StorageProof::new(proof).into_memory_db::<RemoteHasherOf<T, I>>();
-					let value = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
-						&db,
-						&storage_root,
-						&key,
-						None,
-						None,
-					)
-					.ok()
-					.flatten()
-					.ok_or(Error::<T, I>::InvalidProof)?;
-
-					let proxy_definitions = alloc::vec::Vec::<
-						ProxyDefinition<
-							RemoteAccountIdOf<T, I>,
-							RemoteProxyTypeOf<T, I>,
-							RemoteBlockNumberOf<T, I>,
-						>,
-					>::decode(&mut &value[..])
-					.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
-
-					let f = |x: &ProxyDefinition<
-						T::AccountId,
-						T::ProxyType,
-						BlockNumberFor<T>,
-					>|
-					 -> bool {
-						x.delegate == who &&
-							force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
-					};
-
-					proxy_definitions
-						.into_iter()
-						.filter_map(T::RemoteProxy::remote_to_local_proxy_defintion)
-						.find(f)
-						.ok_or(Error::<T, I>::DidNotFindMatchingProxyDefinition)?
+
+					Self::read_proxy_definitions(&db, &storage_root, real)?
 				},
 			};
 
-			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+			let f = |x: &LocalProxyDefinitionOf<T>| -> bool {
+				&x.delegate == delegate &&
+					force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
+			};
 
-			Self::do_proxy(def, real, call);
+			let matching = proxy_definitions.into_iter().filter(f).collect::<Vec<_>>();
+			ensure!(!matching.is_empty(), Error::<T, I>::DidNotFindMatchingProxyDefinition);
+
+			let latest_block =
+				BlockToRoot::<T, I>::get().last().map(|(b, _)| b.clone()).unwrap_or_default();
+			let age = latest_block.saturating_sub(anchor_block);
+
+			let usable = matching
+				.into_iter()
+				.filter(|x| age <= T::MaxProofAge::convert(x.proxy_type.clone()).into())
+				.collect::<Vec<_>>();
+			ensure!(!usable.is_empty(), Error::<T, I>::ProofTooOld);
+
+			Ok(usable)
+		}
+
+		/// Ensure that the proxy of `delegate` for `real` was not revoked after `anchor_block`.
+		fn ensure_not_revoked(
+			delegate: &T::AccountId,
+			real: &T::AccountId,
+			anchor_block: &RemoteBlockNumberOf<T, I>,
+		) -> DispatchResult {
+			let (Some(real_remote), Some(delegate_remote)) = (
+				T::RemoteProxy::local_to_remote_account_id(real),
+				T::RemoteProxy::local_to_remote_account_id(delegate),
+			) else {
+				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
+			};
+
+			if let Some(revoked_at) = RevokedProxies::<T, I>::get(&real_remote, &delegate_remote) {
+				ensure!(*anchor_block >= revoked_at, Error::<T, I>::ProxyRevoked);
+			}
 
 			Ok(())
 		}
 
+		/// Returns the storage root of the remote location to verify the proxy definitions in
+		/// `proof` against, together with the proof nodes of the proxy definitions.
+		fn remote_storage_root(
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<(RemoteHashOf<T, I>, Vec<Vec<u8>>), DispatchError> {
+			match proof {
+				RemoteProxyProof::RelayChain { proof, block } => {
+					ensure!(
+						T::RemoteProxy::remote_para_id().is_none(),
+						Error::<T, I>::UnsupportedProofKind
+					);
+
+					Ok((Self::anchor_storage_root(&block)?, proof))
+				},
+				RemoteProxyProof::Parachain { relay_proof, proof, block } => {
+					let para_id = T::RemoteProxy::remote_para_id()
+						.ok_or(Error::<T, I>::UnsupportedProofKind)?;
+					let relay_storage_root = Self::anchor_storage_root(&block)?;
+
+					let relay_db = sp_trie::StorageProof::new(relay_proof)
+						.into_memory_db::<RemoteHasherOf<T, I>>();
+					let head = Self::read_proof_value(
+						&relay_db,
+						&relay_storage_root,
+						&T::RemoteProxy::para_head_storage_key(para_id),
+					)?;
+					// `HeadData` is encoded as a plain vector of bytes.
+					let head = Vec::<u8>::decode(&mut &head[..])
+						.map_err(|_| Error::<T, I>::ParaHeadDecodingFailed)?;
+					let storage_root = T::RemoteProxy::para_head_to_storage_root(&head)
+						.ok_or(Error::<T, I>::ParaHeadDecodingFailed)?;
+
+					Ok((storage_root, proof))
+				},
+			}
+		}
+
+		/// Read the proxy definitions of `real` from `db` and convert them to local proxy
+		/// definitions.
+		fn read_proxy_definitions(
+			db: &sp_trie::MemoryDB<RemoteHasherOf<T, I>>,
+			storage_root: &RemoteHashOf<T, I>,
+			real: &T::AccountId,
+		) -> Result<Vec<LocalProxyDefinitionOf<T>>, DispatchError> {
+			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(real) else {
+				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
+			};
+
+			let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
+			let value = Self::read_proof_value(db, storage_root, &key)?;
+
+			let proxy_definitions = alloc::vec::Vec::<
+				ProxyDefinition<
+					RemoteAccountIdOf<T, I>,
+					RemoteProxyTypeOf<T, I>,
+					RemoteBlockNumberOf<T, I>,
+				>,
+			>::decode(&mut &value[..])
+			.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
+
+			Ok(proxy_definitions
+				.into_iter()
+				.filter_map(T::RemoteProxy::remote_to_local_proxy_defintion)
+				.collect())
+		}
+
+		/// Returns the storage root of the given anchor `block`.
+		fn anchor_storage_root(
+			block: &RemoteBlockNumberOf<T, I>,
+		) -> Result<RemoteHashOf<T, I>, DispatchError> {
+			let roots = BlockToRoot::<T, I>::get();
+
+			roots
+				.binary_search_by(|(b, _)| b.cmp(block))
+				.map(|pos| roots[pos].1.clone())
+				.map_err(|_| Error::<T, I>::UnknownProofAnchorBlock.into())
+		}
+
+		/// Read the value at `key` from the proof nodes in `db` against `storage_root`.
+		fn read_proof_value(
+			db: &sp_trie::MemoryDB<RemoteHasherOf<T, I>>,
+			storage_root: &RemoteHashOf<T, I>,
+			key: &[u8],
+		) -> Result<Vec<u8>, DispatchError> {
+			sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(db, storage_root, key, None, None)
+				.ok()
+				.flatten()
+				.ok_or(Error::<T, I>::InvalidProof.into())
+		}
+
+		/// Retain the announcements of `delegate` for which `f` returns `true`.
+		///
+		/// Fails if no announcement was removed. The deposit of `delegate` is adjusted to the
+		/// remaining announcements.
+		fn edit_announcements<F: FnMut(&RemoteAnnouncementOf<T>) -> bool>(
+			delegate: &T::AccountId,
+			f: F,
+		) -> DispatchResult {
+			Announcements::<T, I>::try_mutate_exists(delegate, |x| {
+				let (mut pending, old_deposit) =
+					x.take().ok_or(Error::<T, I>::AnnouncementNotFound)?;
+				let orig_pending_len = pending.len();
+				pending.retain(f);
+				ensure!(orig_pending_len > pending.len(), Error::<T, I>::AnnouncementNotFound);
+
+				let new_deposit =
+					Self::rejig_announcement_deposit(delegate, old_deposit, pending.len())?;
+				if !pending.is_empty() {
+					*x = Some((pending, new_deposit));
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Adjust the reserved announcement deposit of `who` to cover `len` announcements.
+		///
+		/// Returns the new deposit.
+		fn rejig_announcement_deposit(
+			who: &T::AccountId,
+			old_deposit: BalanceOf<T>,
+			len: usize,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let new_deposit = if len == 0 {
+				BalanceOf::<T>::zero()
+			} else {
+				<T as pallet_proxy::Config>::AnnouncementDepositBase::get().saturating_add(
+					<T as pallet_proxy::Config>::AnnouncementDepositFactor::get()
+						.saturating_mul((len as u32).into()),
+				)
+			};
+
+			if new_deposit > old_deposit {
+				<T as pallet_proxy::Config>::Currency::reserve(
+					who,
+					new_deposit.saturating_sub(old_deposit),
+				)?;
+			} else if new_deposit < old_deposit {
+				let excess = old_deposit.saturating_sub(new_deposit);
+				let remaining_unreserved =
+					<T as pallet_proxy::Config>::Currency::unreserve(who, excess);
+				if !remaining_unreserved.is_zero() {
+					frame_support::defensive!("Failed to unreserve full amount.");
+				}
+			}
+
+			Ok(new_deposit)
+		}
+
 		// TODO: Make upstream public and use that one.
 		fn do_proxy(
-			def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+			def: LocalProxyDefinitionOf<T>,
 			real: T::AccountId,
 			call: <T as pallet_proxy::Config>::RuntimeCall,
 		) {
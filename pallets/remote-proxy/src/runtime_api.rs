@@ -0,0 +1,82 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for building and validating remote proxy proofs.
+
+use crate::{ParaId, ProxyDefinition, RemoteProxyProof};
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
+
+/// The remote storage keys that need to be proven to use a remote proxy.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct RemoteProxyStorageKeys {
+	/// The key of the proxy definitions of the `real` account in the state of the remote
+	/// location.
+	pub proxy_definitions: Vec<u8>,
+	/// The key of the head of the remote parachain in the relay chain state.
+	///
+	/// `None` if the remote location is the relay chain.
+	pub para_head: Option<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for building and validating remote proxy proofs.
+	///
+	/// A runtime can have one instance of the remote proxy pallet per remote location. Every
+	/// function takes the `remote_para_id` of the instance to use, `None` selects the instance
+	/// that uses the relay chain as remote location (see
+	/// [`RemoteProxyInterface::remote_para_id`](crate::RemoteProxyInterface::remote_para_id)).
+	pub trait RemoteProxyApi<AccountId, ProxyType, BlockNumber, RemoteBlockNumber>
+	where
+		AccountId: Codec,
+		ProxyType: Codec,
+		BlockNumber: Codec,
+		RemoteBlockNumber: Codec,
+	{
+		/// Returns the remote storage keys that need to be proven to use a remote proxy of
+		/// `real`.
+		///
+		/// Returns `None` if `real` can not be converted to a remote account or if there is no
+		/// instance for `remote_para_id`.
+		fn remote_storage_keys(
+			remote_para_id: Option<ParaId>,
+			real: AccountId,
+		) -> Option<RemoteProxyStorageKeys>;
+
+		/// Returns the anchor blocks that are currently accepted for proofs.
+		///
+		/// If `proxy_type` is given, only the anchor blocks that are recent enough for proxies of
+		/// this type are returned. Returns no anchor blocks if there is no instance for
+		/// `remote_para_id`.
+		fn acceptable_anchor_blocks(
+			remote_para_id: Option<ParaId>,
+			proxy_type: Option<ProxyType>,
+		) -> Vec<RemoteBlockNumber>;
+
+		/// Validate the given remote `proof` about a proxy of `delegate` for `real`.
+		///
+		/// Returns the proxy definitions that `delegate` can use with `proof` or the reason why
+		/// `proof` can not be used.
+		fn validate_proof(
+			remote_para_id: Option<ParaId>,
+			real: AccountId,
+			delegate: AccountId,
+			proof: RemoteProxyProof<RemoteBlockNumber>,
+		) -> Result<Vec<ProxyDefinition<AccountId, ProxyType, BlockNumber>>, DispatchError>;
+	}
+}
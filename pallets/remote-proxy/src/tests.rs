@@ -22,17 +22,18 @@ use codec::{Decode, DecodeWithMemTracking};
 use cumulus_pallet_parachain_system::OnSystemEvent;
 use frame_support::{
 	assert_err, assert_ok, construct_runtime, derive_impl,
-	traits::{Contains, Currency},
+	instances::Instance1,
+	traits::{Contains, Currency, Get, Hooks},
 };
-use frame_system::Call as SystemCall;
+use frame_system::{Call as SystemCall, EnsureRoot};
 use pallet_balances::Call as BalancesCall;
 use pallet_proxy::{Error as ProxyError, Event as ProxyEvent};
 use pallet_utility::Call as UtilityCall;
 use sp_core::{ConstU32, ConstU64, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
-	traits::{BlakeTwo256, Dispatchable},
-	BoundedVec, BuildStorage,
+	traits::{BlakeTwo256, Convert, Dispatchable, Hash},
+	BoundedVec, BuildStorage, DispatchError,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -44,6 +45,7 @@ construct_runtime!(
 		Proxy: pallet_proxy,
 		Utility: pallet_utility,
 		RemoteProxy: remote_proxy,
+		RemoteProxyPara: remote_proxy<Instance1>,
 	}
 );
 
@@ -154,6 +156,10 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 		Some(*local)
 	}
 
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<u64> {
+		Some(delay)
+	}
+
 	fn remote_to_local_proxy_defintion(
 		remote: ProxyDefinition<
 			Self::RemoteAccountId,
@@ -168,6 +174,14 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 	fn create_remote_proxy_proof(
 		caller: &u64,
 		proxy: &u64,
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, u64, H256) {
+		Self::create_remote_proxy_batch_proof(caller, core::slice::from_ref(proxy))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxy_batch_proof(
+		caller: &u64,
+		proxies: &[u64],
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, u64, H256) {
 		use sp_trie::TrieMut;
 
@@ -181,8 +195,10 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 			delay: 0,
 		}];
 
-		trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-			.unwrap();
+		for proxy in proxies {
+			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+				.unwrap();
+		}
 		drop(trie);
 
 		(
@@ -196,12 +212,136 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 	}
 }
 
+/// `Any` proxies need to prove against one of the latest three storage roots, all others may use
+/// older ones.
+pub struct MaxProofAge;
+
+impl Convert<ProxyType, u32> for MaxProofAge {
+	fn convert(proxy_type: ProxyType) -> u32 {
+		match proxy_type {
+			ProxyType::Any => 2,
+			ProxyType::JustTransfer | ProxyType::JustUtility => 10,
+		}
+	}
+}
+
 impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxStorageRootsToKeep = ConstU32<10>;
+	type MaxProofAccounts = ConstU32<10>;
+	type MaxProofAccountsPerSigner = ConstU32<5>;
+	type MaxProofAge = MaxProofAge;
+	type RevocationOrigin = EnsureRoot<u64>;
+	type MaxRevocations = ConstU32<10>;
 	type RemoteProxy = RemoteProxyImpl;
 	type WeightInfo = ();
 }
 
+/// The remote parachain used by [`RemoteProxyParaImpl`].
+const REMOTE_PARA_ID: u32 = 1000;
+
+pub struct RemoteProxyParaImpl;
+
+impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyParaImpl {
+	type RemoteAccountId = u64;
+	type RemoteProxyType = ProxyType;
+	type RemoteBlockNumber = u64;
+	type RemoteHash = H256;
+	type RemoteHasher = BlakeTwo256;
+
+	fn block_to_storage_root(
+		validation_data: &PersistedValidationData,
+	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as Hasher>::Out)> {
+		Some((validation_data.relay_parent_number as _, validation_data.relay_parent_storage_root))
+	}
+
+	fn remote_para_id() -> Option<ParaId> {
+		Some(REMOTE_PARA_ID.into())
+	}
+
+	fn local_to_remote_account_id(local: &u64) -> Option<Self::RemoteAccountId> {
+		Some(*local)
+	}
+
+	fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<u64> {
+		Some(delay)
+	}
+
+	fn remote_to_local_proxy_defintion(
+		remote: ProxyDefinition<
+			Self::RemoteAccountId,
+			Self::RemoteProxyType,
+			Self::RemoteBlockNumber,
+		>,
+	) -> Option<ProxyDefinition<u64, ProxyType, u64>> {
+		Some(remote)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxy_proof(
+		caller: &u64,
+		proxy: &u64,
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, u64, H256) {
+		Self::create_remote_proxy_batch_proof(caller, core::slice::from_ref(proxy))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxy_batch_proof(
+		caller: &u64,
+		proxies: &[u64],
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, u64, H256) {
+		let (proof, block, root) =
+			RemoteProxyImpl::create_remote_proxy_batch_proof(caller, proxies);
+		let RemoteProxyProof::RelayChain { proof, .. } = proof else {
+			unreachable!("`RemoteProxyImpl` only creates relay chain proofs; qed")
+		};
+		let (relay_proof, relay_root) = relay_state_with_para_head(root);
+
+		(RemoteProxyProof::Parachain { relay_proof, proof, block }, block, relay_root)
+	}
+}
+
+impl Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxStorageRootsToKeep = ConstU32<10>;
+	type MaxProofAccounts = ConstU32<10>;
+	type MaxProofAccountsPerSigner = ConstU32<5>;
+	type MaxProofAge = MaxProofAge;
+	type RevocationOrigin = EnsureRoot<u64>;
+	type MaxRevocations = ConstU32<10>;
+	type RemoteProxy = RemoteProxyParaImpl;
+	type WeightInfo = ();
+}
+
+/// Build a relay chain state that contains the head of [`REMOTE_PARA_ID`] with the given
+/// `para_storage_root`.
+///
+/// Returns the proof of the para head and the relay chain storage root.
+fn relay_state_with_para_head(para_storage_root: H256) -> (Vec<Vec<u8>>, H256) {
+	use sp_trie::TrieMut;
+
+	let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+	let mut trie =
+		sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+
+	let header = sp_runtime::generic::Header::<u64, BlakeTwo256>::new(
+		5,
+		H256::zero(),
+		para_storage_root,
+		H256::repeat_byte(1),
+		Default::default(),
+	);
+
+	trie.insert(
+		&RemoteProxyParaImpl::para_head_storage_key(REMOTE_PARA_ID.into()),
+		&header.encode().encode(),
+	)
+	.unwrap();
+	drop(trie);
+
+	(db.drain().into_values().map(|d| d.0).collect(), root)
+}
+
 pub fn new_test_ext() -> TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -638,3 +778,593 @@ fn clean_up_works_and_old_blocks_are_rejected() {
 			.for_each(|(b, _)| assert!(*b >= 31 && *b <= 40));
 	});
 }
+
+#[test]
+fn remote_proxy_announced_works() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 2));
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(1)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let proof =
+			RemoteProxyProof::RelayChain { proof: proof.into_iter_nodes().collect(), block: 1 };
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		// Time-delayed proxies can not be used without an announcement.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(2),
+				1,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		// Only a remote proxy of `real` can announce.
+		assert_err!(
+			RemoteProxy::announce(RuntimeOrigin::signed(3), 1, call_hash, proof.clone()),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash, proof.clone()));
+		System::assert_last_event(Event::<Test>::Announced { real: 1, proxy: 2, call_hash }.into());
+		assert_eq!(Balances::reserved_balance(2), 2);
+
+		// The delay did not pass yet.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(3),
+				2,
+				1,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		System::set_block_number(3);
+
+		// Only the announced call can be dispatched.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(3),
+				2,
+				1,
+				None,
+				Box::new(call_transfer(6, 2)),
+				proof.clone()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		assert_ok!(RemoteProxy::remote_proxy_announced(
+			RuntimeOrigin::signed(3),
+			2,
+			1,
+			None,
+			call.clone(),
+			proof.clone()
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Announcements::<Test>::contains_key(2));
+
+		// The announcement is consumed.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(RuntimeOrigin::signed(3), 2, 1, None, call, proof),
+			Error::<Test>::Unannounced
+		);
+	});
+}
+
+#[test]
+fn remote_proxy_announcements_can_be_removed_and_rejected() {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 2));
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(1)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call_hash = BlakeTwo256::hash_of(&call_transfer(6, 1));
+		let call_hash2 = BlakeTwo256::hash_of(&call_transfer(6, 2));
+		let call_hash3 = BlakeTwo256::hash_of(&call_transfer(6, 3));
+		let proof =
+			RemoteProxyProof::RelayChain { proof: proof.into_iter_nodes().collect(), block: 1 };
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash, proof.clone()));
+		assert_ok!(RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash2, proof.clone()));
+		assert_eq!(Balances::reserved_balance(2), 3);
+		assert_err!(
+			RemoteProxy::announce(RuntimeOrigin::signed(2), 1, call_hash3, proof),
+			Error::<Test>::TooManyAnnouncements
+		);
+
+		assert_ok!(RemoteProxy::remove_announcement(RuntimeOrigin::signed(2), 1, call_hash));
+		System::assert_last_event(
+			Event::<Test>::AnnouncementRemoved { real: 1, proxy: 2, call_hash }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 2);
+		assert_err!(
+			RemoteProxy::remove_announcement(RuntimeOrigin::signed(2), 1, call_hash),
+			Error::<Test>::AnnouncementNotFound
+		);
+
+		// Only `real` can reject the announcement.
+		assert_err!(
+			RemoteProxy::reject_announcement(RuntimeOrigin::signed(3), 2, call_hash2),
+			Error::<Test>::AnnouncementNotFound
+		);
+		assert_ok!(RemoteProxy::reject_announcement(RuntimeOrigin::signed(1), 2, call_hash2));
+		System::assert_last_event(
+			Event::<Test>::AnnouncementRejected { real: 1, proxy: 2, call_hash: call_hash2 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Announcements::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn remote_proxy_parachain_proof_works() {
+	let mut ext = new_test_ext();
+
+	let anon = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		Proxy::pure_account(&1, &ProxyType::Any, 0, None)
+	});
+
+	let proof: Vec<Vec<u8>> = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(anon)],
+	)
+	.unwrap()
+	.into_iter_nodes()
+	.collect();
+	let para_root = *ext.as_backend().root();
+	let (relay_proof, relay_root) = relay_state_with_para_head(para_root);
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+
+		RemoteProxyPara::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: relay_root,
+			max_pov_size: 5000000,
+		});
+
+		// The proxy lives on the remote parachain and not on the relay chain.
+		assert_err!(
+			RemoteProxyPara::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::RelayChain { proof: proof.clone(), block: 1 }
+			),
+			Error::<Test, Instance1>::UnsupportedProofKind
+		);
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::Parachain {
+					relay_proof: relay_proof.clone(),
+					proof: proof.clone(),
+					block: 1
+				}
+			),
+			Error::<Test>::UnsupportedProofKind
+		);
+
+		// The para head needs to be proven.
+		assert_err!(
+			RemoteProxyPara::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::Parachain {
+					relay_proof: Vec::new(),
+					proof: proof.clone(),
+					block: 1
+				}
+			),
+			Error::<Test, Instance1>::InvalidProof
+		);
+
+		assert_ok!(RemoteProxyPara::remote_proxy(
+			RuntimeOrigin::signed(1),
+			anon,
+			None,
+			call,
+			RemoteProxyProof::Parachain { relay_proof, proof, block: 1 }
+		));
+
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+	});
+}
+
+#[test]
+fn remote_proxy_definitions_can_be_registered_for_multiple_accounts() {
+	let mut ext = new_test_ext();
+
+	let (anon, anon2) = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 12); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 1));
+
+		(
+			Proxy::pure_account(&1, &ProxyType::Any, 0, None),
+			Proxy::pure_account(&1, &ProxyType::Any, 1, None),
+		)
+	});
+
+	// One proof for both accounts.
+	let proof: Vec<Vec<u8>> = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[
+			pallet_proxy::Proxies::<Test>::hashed_key_for(anon),
+			pallet_proxy::Proxies::<Test>::hashed_key_for(anon2),
+		],
+	)
+	.unwrap()
+	.into_iter_nodes()
+	.collect();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), anon2, 5));
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		// All accounts need to be covered by the proof.
+		assert_err!(
+			RemoteProxy::register_remote_proxy_definitions(
+				RuntimeOrigin::signed(5),
+				BoundedVec::truncate_from(vec![anon, 4]),
+				RemoteProxyProof::RelayChain { proof: proof.clone(), block: 1 }
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		// At most `MaxProofAccounts` accounts can be cached per block.
+		VerifiedProxyDefinitionsCount::<Test>::put(9);
+		assert_err!(
+			RemoteProxy::register_remote_proxy_definitions(
+				RuntimeOrigin::signed(5),
+				BoundedVec::truncate_from(vec![anon, anon2]),
+				RemoteProxyProof::RelayChain { proof: proof.clone(), block: 1 }
+			),
+			Error::<Test>::TooManyVerifiedProxyDefinitions
+		);
+		VerifiedProxyDefinitionsCount::<Test>::kill();
+
+		// At most `MaxProofAccountsPerSigner` accounts can be cached per block by one signer.
+		VerifiedProxyDefinitionsBySigner::<Test>::insert(5, 4);
+		assert_err!(
+			RemoteProxy::register_remote_proxy_definitions(
+				RuntimeOrigin::signed(5),
+				BoundedVec::truncate_from(vec![anon, anon2]),
+				RemoteProxyProof::RelayChain { proof: proof.clone(), block: 1 }
+			),
+			Error::<Test>::TooManyVerifiedProxyDefinitionsBySigner
+		);
+		VerifiedProxyDefinitionsBySigner::<Test>::remove(5);
+
+		assert_ok!(RemoteProxy::register_remote_proxy_definitions(
+			RuntimeOrigin::signed(5),
+			BoundedVec::truncate_from(vec![anon, anon2]),
+			RemoteProxyProof::RelayChain { proof: proof.clone(), block: 1 }
+		));
+		assert!(VerifiedProxyDefinitions::<Test>::contains_key(1, anon));
+		assert!(VerifiedProxyDefinitions::<Test>::contains_key(1, anon2));
+		assert_eq!(VerifiedProxyDefinitionsCount::<Test>::get(), 2);
+		assert_eq!(VerifiedProxyDefinitionsBySigner::<Test>::get(5), 2);
+
+		// Registering cached accounts again doesn't count them twice.
+		assert_ok!(RemoteProxy::register_remote_proxy_definitions(
+			RuntimeOrigin::signed(6),
+			BoundedVec::truncate_from(vec![anon]),
+			RemoteProxyProof::RelayChain { proof, block: 1 }
+		));
+		assert_eq!(VerifiedProxyDefinitionsCount::<Test>::get(), 2);
+		assert_eq!(VerifiedProxyDefinitionsBySigner::<Test>::get(6), 0);
+
+		// The cached definitions are used without verifying the proof again.
+		let cached_proof = RemoteProxyProof::RelayChain { proof: Vec::new(), block: 1 };
+		assert_ok!(RuntimeCall::from(UtilityCall::batch_all {
+			calls: vec![
+				crate::Call::register_remote_proxy_proof { proof: cached_proof.clone() }.into(),
+				crate::Call::remote_proxy_with_registered_proof {
+					real: anon,
+					force_proxy_type: None,
+					call: call.clone(),
+				}
+				.into()
+			]
+		})
+		.dispatch(RuntimeOrigin::signed(1)));
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(1),
+			anon2,
+			None,
+			call.clone(),
+			cached_proof.clone()
+		));
+		assert_eq!(Balances::free_balance(6), 2);
+
+		// Only the delegate can use the cached definitions.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(2),
+				anon,
+				None,
+				call.clone(),
+				cached_proof.clone()
+			),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		// The cache is only valid for the anchor block of the proof.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				RemoteProxyProof::RelayChain { proof: Vec::new(), block: 2 }
+			),
+			Error::<Test>::UnknownProofAnchorBlock
+		);
+
+		// The cache is cleared at the start of the next block.
+		assert_eq!(
+			RemoteProxy::on_initialize(2),
+			<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 4)
+		);
+		assert!(!VerifiedProxyDefinitions::<Test>::contains_key(1, anon));
+		assert!(!VerifiedProxyDefinitionsBySigner::<Test>::contains_key(5));
+		assert!(!VerifiedProxyDefinitions::<Test>::contains_key(1, anon2));
+		assert_eq!(VerifiedProxyDefinitionsCount::<Test>::get(), 0);
+		assert_err!(
+			RemoteProxy::remote_proxy(RuntimeOrigin::signed(1), anon, None, call, cached_proof),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+/// Let `3` add `1` as `Any` and `2` as `JustTransfer` proxy.
+///
+/// Returns the proof of the proxy definitions of `3` and the storage root.
+fn proof_of_any_and_just_transfer_proxy() -> (Vec<Vec<u8>>, H256) {
+	let mut ext = new_test_ext();
+
+	ext.execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(3), 1, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(3), 2, ProxyType::JustTransfer, 0));
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(3)],
+	)
+	.unwrap()
+	.into_iter_nodes()
+	.collect();
+
+	(proof, *ext.as_backend().root())
+}
+
+fn set_storage_root(block: u32, root: H256) {
+	RemoteProxy::on_validation_data(&PersistedValidationData {
+		parent_head: vec![].into(),
+		relay_parent_number: block,
+		relay_parent_storage_root: root,
+		max_pov_size: 5000000,
+	});
+}
+
+#[test]
+fn max_proof_age_depends_on_proxy_type() {
+	let (proof, root) = proof_of_any_and_just_transfer_proxy();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		set_storage_root(1, root);
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(1),
+			3,
+			None,
+			call.clone(),
+			proof.clone()
+		));
+
+		// `Any` proxies may use proofs that are a few storage roots old, as the proof is created
+		// before the transaction is included.
+		set_storage_root(2, H256::zero());
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(1),
+			3,
+			None,
+			call.clone(),
+			proof.clone()
+		));
+		set_storage_root(3, H256::zero());
+		assert_ok!(RemoteProxy::remote_proxy(
+			RuntimeOrigin::signed(1),
+			3,
+			None,
+			call.clone(),
+			proof.clone()
+		));
+
+		// But not older ones.
+		set_storage_root(4, H256::zero());
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(1),
+				3,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::ProofTooOld
+		);
+
+		// `JustTransfer` proxies may use older ones.
+		assert_ok!(RemoteProxy::remote_proxy(RuntimeOrigin::signed(2), 3, None, call, proof));
+		assert_eq!(Balances::free_balance(6), 4);
+	});
+}
+
+#[test]
+fn revoked_remote_proxies_are_rejected() {
+	let (proof, root) = proof_of_any_and_just_transfer_proxy();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+		let revocations = BoundedVec::truncate_from(vec![RemoteProxyRevocation {
+			real: 3,
+			delegate: 2,
+			block: 2,
+		}]);
+
+		set_storage_root(1, root);
+		set_storage_root(2, H256::zero());
+
+		assert_err!(
+			RemoteProxy::revoke_remote_proxies(RuntimeOrigin::signed(3), revocations.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(RemoteProxy::revoke_remote_proxies(RuntimeOrigin::root(), revocations));
+		assert_eq!(RevokedProxies::<Test>::get(3, 2), Some(2));
+
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(2),
+				3,
+				None,
+				call.clone(),
+				proof.clone()
+			),
+			Error::<Test>::ProxyRevoked
+		);
+
+		// The revocation is still required while proofs before it can be verified.
+		assert_err!(
+			RemoteProxy::remove_expired_revocation(RuntimeOrigin::signed(5), 3, 2),
+			Error::<Test>::RevocationStillRequired
+		);
+
+		for block in 3..=12 {
+			set_storage_root(block, H256::zero());
+		}
+
+		assert_ok!(RemoteProxy::remove_expired_revocation(RuntimeOrigin::signed(5), 3, 2));
+		assert!(RevokedProxies::<Test>::get(3, 2).is_none());
+		assert_err!(
+			RemoteProxy::remove_expired_revocation(RuntimeOrigin::signed(5), 3, 2),
+			Error::<Test>::RevocationNotFound
+		);
+	});
+}
+
+#[test]
+fn runtime_api_functions_work() {
+	let (proof, root) = proof_of_any_and_just_transfer_proxy();
+
+	new_test_ext().execute_with(|| {
+		let proof = RemoteProxyProof::RelayChain { proof, block: 1 };
+
+		assert_eq!(
+			RemoteProxy::remote_storage_keys(&3),
+			Some(RemoteProxyStorageKeys {
+				proxy_definitions: pallet_proxy::Proxies::<Test>::hashed_key_for(3),
+				para_head: None,
+			})
+		);
+		assert!(RemoteProxyPara::remote_storage_keys(&3).unwrap().para_head.is_some());
+		assert_eq!(RemoteProxy::remote_para_id(), None);
+		assert!(RemoteProxyPara::remote_para_id().is_some());
+
+		set_storage_root(1, root);
+		for block in 2..=4 {
+			set_storage_root(block, H256::zero());
+		}
+
+		assert_eq!(RemoteProxy::acceptable_anchor_blocks(None), vec![1, 2, 3, 4]);
+		assert_eq!(RemoteProxy::acceptable_anchor_blocks(Some(ProxyType::Any)), vec![2, 3, 4]);
+		assert_eq!(
+			RemoteProxy::acceptable_anchor_blocks(Some(ProxyType::JustTransfer)),
+			vec![1, 2, 3, 4]
+		);
+
+		assert_eq!(
+			RemoteProxy::validate_remote_proxy_proof(&3, &2, proof.clone()),
+			Ok(vec![ProxyDefinition {
+				delegate: 2,
+				proxy_type: ProxyType::JustTransfer,
+				delay: 0,
+			}])
+		);
+		assert_err!(
+			RemoteProxy::validate_remote_proxy_proof(&3, &1, proof.clone()),
+			Error::<Test>::ProofTooOld
+		);
+		assert_err!(
+			RemoteProxy::validate_remote_proxy_proof(&3, &4, proof),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+	});
+}
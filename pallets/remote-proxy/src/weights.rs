@@ -0,0 +1,128 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_remote_proxy`.
+//!
+//! The calls that were added after the weights of the runtimes were generated have default weights
+//! in [`WeightInfo`]. They account for the storage accesses of the call and use generous estimates
+//! for the execution time and the proof size. Weights generated from the benchmarks override them.
+
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+
+/// Weight functions needed for `pallet_remote_proxy`.
+pub trait WeightInfo {
+	fn remote_proxy_with_registered_proof() -> Weight;
+	fn register_remote_proxy_proof() -> Weight;
+	fn remote_proxy() -> Weight;
+
+	// Reads the proof storage items, the local block number and changes `Announcements` and the
+	// deposit of the caller.
+	fn announce() -> Weight {
+		Weight::from_parts(120_000_000, 16_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	// Changes `Announcements` and the deposit of the caller.
+	fn remove_announcement() -> Weight {
+		Weight::from_parts(90_000_000, 8_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	// Changes `Announcements` and the deposit of the delegate.
+	fn reject_announcement() -> Weight {
+		Weight::from_parts(90_000_000, 8_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	// Reads the proof storage items, the local block number and changes `Announcements` and the
+	// deposit of the delegate.
+	fn remote_proxy_announced() -> Weight {
+		Weight::from_parts(130_000_000, 16_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	// Reads `BlockToRoot`, changes `VerifiedProxyDefinitionsCount`, the
+	// `VerifiedProxyDefinitionsBySigner` entry of the signer and one `VerifiedProxyDefinitions`
+	// entry per account. Decoding and hashing the nodes of the proof is linear in its size `p`.
+	fn register_remote_proxy_definitions(n: u32, p: u32) -> Weight {
+		Weight::from_parts(40_000_000, 2_000)
+			.saturating_add(Weight::from_parts(20_000_000, 4_000).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads_writes(n.into(), n.into()))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	// Reads and writes one `RevokedProxies` entry per account.
+	fn revoke_remote_proxies(n: u32) -> Weight {
+		Weight::from_parts(15_000_000, 1_000)
+			.saturating_add(Weight::from_parts(10_000_000, 3_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads_writes(n.into(), n.into()))
+	}
+
+	// Reads `BlockToRoot` and removes one `RevokedProxies` entry.
+	fn remove_expired_revocation() -> Weight {
+		Weight::from_parts(30_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
+
+impl WeightInfo for () {
+	fn remote_proxy_with_registered_proof() -> Weight {
+		Weight::MAX
+	}
+
+	fn register_remote_proxy_proof() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_proxy() -> Weight {
+		Weight::MAX
+	}
+
+	fn announce() -> Weight {
+		Weight::MAX
+	}
+
+	fn remove_announcement() -> Weight {
+		Weight::MAX
+	}
+
+	fn reject_announcement() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_proxy_announced() -> Weight {
+		Weight::MAX
+	}
+
+	fn register_remote_proxy_definitions(_: u32, _: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn revoke_remote_proxies(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn remove_expired_revocation() -> Weight {
+		Weight::MAX
+	}
+}
@@ -17,11 +17,20 @@
 //! Shared code between the Kusama nd Polkadot RC Runtimes.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use polkadot_primitives::Balance;
 use sp_runtime::{Perquintill, Saturating};
 
+pub mod referenda;
+
 /// Extra runtime APIs for kusama runtime.
 pub mod apis {
+	use alloc::vec::Vec;
+	use codec::Codec;
+	use sp_runtime::{Perbill, Perquintill};
+
 	/// Information about the current inflation rate of the system.
 	///
 	/// Both fields should be treated as best-effort, given that the inflation rate might not be
@@ -38,13 +47,254 @@ pub mod apis {
 		pub next_mint: (polkadot_primitives::Balance, polkadot_primitives::Balance),
 	}
 
+	/// The projected payout of a future era.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct EraPayoutProjection {
+		/// The index of the era.
+		pub era: u32,
+		/// The amount that goes to stakers.
+		pub staking_payout: polkadot_primitives::Balance,
+		/// The leftover that is usually forwarded to the treasury.
+		pub rest: polkadot_primitives::Balance,
+		/// The stakable issuance after the payout of the era.
+		pub total_stakable: polkadot_primitives::Balance,
+	}
+
+	/// Hypothetical values that replace the parameters of the era payout in a projection.
+	///
+	/// `None` keeps the current value of the parameter. The first parameters are the ones of
+	/// [`crate::EraPayoutParams`], the others are the ones of a fixed yearly emission.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Default)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct EraPayoutOverrides {
+		/// Ideal stake ratio.
+		pub ideal_stake: Option<Perquintill>,
+		/// Minimum inflation rate.
+		pub min_annual_inflation: Option<Perquintill>,
+		/// Maximum inflation rate.
+		pub max_annual_inflation: Option<Perquintill>,
+		/// Falloff used to calculate era payouts.
+		pub falloff: Option<Perquintill>,
+		/// The yearly emission as a fraction of the base issuance.
+		pub annual_rate: Option<Perquintill>,
+		/// The fraction of every era emission that goes to the treasury.
+		pub treasury_share: Option<Perquintill>,
+	}
+
+	impl EraPayoutOverrides {
+		/// Returns `true` if no parameter is overridden.
+		pub fn is_empty(&self) -> bool {
+			!self.overrides_era_payout_params() && !self.overrides_fixed_emission()
+		}
+
+		/// Returns `true` if a parameter of [`crate::EraPayoutParams`] is overridden.
+		pub fn overrides_era_payout_params(&self) -> bool {
+			self.ideal_stake.is_some() ||
+				self.min_annual_inflation.is_some() ||
+				self.max_annual_inflation.is_some() ||
+				self.falloff.is_some()
+		}
+
+		/// Returns `true` if a parameter of a fixed yearly emission is overridden.
+		pub fn overrides_fixed_emission(&self) -> bool {
+			self.annual_rate.is_some() || self.treasury_share.is_some()
+		}
+
+		/// Replace the overridden parameters in `params`.
+		pub fn apply(&self, params: &mut crate::EraPayoutParams) {
+			params.ideal_stake = self.ideal_stake.unwrap_or(params.ideal_stake);
+			params.min_annual_inflation =
+				self.min_annual_inflation.unwrap_or(params.min_annual_inflation);
+			params.max_annual_inflation =
+				self.max_annual_inflation.unwrap_or(params.max_annual_inflation);
+			params.falloff = self.falloff.unwrap_or(params.falloff);
+		}
+	}
+
+	/// A track of a referenda pallet.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct ReferendaTrack<TrackId, Balance, Moment> {
+		/// The id of the track.
+		pub id: TrackId,
+		/// The name of the track.
+		pub name: alloc::string::String,
+		/// The maximum number of referenda that can be decided at once.
+		pub max_deciding: u32,
+		/// The amount that needs to be deposited to start the decision period of a referendum.
+		pub decision_deposit: Balance,
+		/// The minimum time a referendum has to be prepared before it can be decided.
+		pub prepare_period: Moment,
+		/// The maximum time a referendum can be decided.
+		pub decision_period: Moment,
+		/// The time a referendum has to stay passing to be approved.
+		pub confirm_period: Moment,
+		/// The minimum time between the approval of a referendum and its enactment.
+		pub min_enactment_period: Moment,
+		/// The minimum approval over the decision period.
+		pub min_approval: pallet_referenda::Curve,
+		/// The minimum support over the decision period.
+		pub min_support: pallet_referenda::Curve,
+	}
+
+	/// The thresholds a referendum has to reach to be passing.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct ReferendaThresholds {
+		/// The minimum share of aye votes of all votes.
+		pub min_approval: Perbill,
+		/// The minimum share of aye votes of the total issuance.
+		pub min_support: Perbill,
+	}
+
 	sp_api::decl_runtime_apis! {
+		/// API to query the tracks of the referenda pallets and to evaluate their decision curves.
+		///
+		/// A referenda pallet is identified by its index in the runtime.
+		pub trait ReferendaTracks<TrackId, Balance, Moment>
+		where
+			TrackId: Codec,
+			Balance: Codec,
+			Moment: Codec,
+		{
+			/// Returns all tracks of the referenda pallet at `pallet_index`.
+			///
+			/// Returns `None` if there is no referenda pallet at `pallet_index`.
+			fn referenda_tracks(
+				pallet_index: u8,
+			) -> Option<Vec<ReferendaTrack<TrackId, Balance, Moment>>>;
+
+			/// Returns the thresholds of `track` after the `elapsed` share of its decision period.
+			///
+			/// Returns `None` if there is no referenda pallet at `pallet_index` or no such track.
+			fn referenda_thresholds(
+				pallet_index: u8,
+				track: TrackId,
+				elapsed: Perbill,
+			) -> Option<ReferendaThresholds>;
+
+			/// Returns the time after the start of the decision period until a referendum on
+			/// `track` with a constant `approval` and `support` is approved, including its
+			/// confirmation.
+			///
+			/// Returns `None` if the referendum would never be passing within the decision period,
+			/// or if there is no referenda pallet at `pallet_index` or no such track.
+			fn referenda_time_to_pass(
+				pallet_index: u8,
+				track: TrackId,
+				approval: Perbill,
+				support: Perbill,
+			) -> Option<Moment>;
+		}
+
+		#[api_version(2)]
 		pub trait Inflation {
 			/// Return the current estimates of the inflation amount.
 			///
 			/// This is marked as experimental in light of RFC#89. Nonetheless, its usage is highly
 			/// recommended over trying to read-storage, or re-create the onchain logic.
 			fn experimental_inflation_prediction_info() -> InflationInfo;
+
+			/// Project the payouts of the next `eras` eras, starting with the active era, under the
+			/// current state.
+			///
+			/// The staked amount is assumed to stay constant, while the minted amounts are added to
+			/// the stakable issuance. At most [`MAX_PROJECTED_ERAS`](crate::MAX_PROJECTED_ERAS) eras
+			/// are projected.
+			#[api_version(2)]
+			fn experimental_inflation_projection(eras: u32) -> Vec<EraPayoutProjection>;
+
+			/// Project the payouts of the next `eras` eras like
+			/// [`Self::experimental_inflation_projection`], but with the parameters of the era
+			/// payout replaced by `overrides`.
+			///
+			/// Returns `None` if `overrides` does not apply to the era payout of the runtime.
+			#[api_version(2)]
+			fn experimental_inflation_what_if(
+				overrides: EraPayoutOverrides,
+				eras: u32,
+			) -> Option<Vec<EraPayoutProjection>>;
+		}
+	}
+}
+
+/// The maximum number of eras that are projected by [`project_era_payouts`].
+pub const MAX_PROJECTED_ERAS: u32 = 2048;
+
+/// Project the payouts of `eras` consecutive eras, starting with `first_era`.
+///
+/// `era_payout` returns the staking payout and the leftover of an era, given its index and the
+/// stakable issuance at its start. The minted amounts increase the stakable issuance of the
+/// following eras. At most [`MAX_PROJECTED_ERAS`] eras are projected.
+pub fn project_era_payouts(
+	first_era: u32,
+	eras: u32,
+	mut total_stakable: Balance,
+	mut era_payout: impl FnMut(u32, Balance) -> (Balance, Balance),
+) -> Vec<apis::EraPayoutProjection> {
+	(0..eras.min(MAX_PROJECTED_ERAS))
+		.map(|i| {
+			let era = first_era.saturating_add(i);
+			let (staking_payout, rest) = era_payout(era, total_stakable);
+			total_stakable = total_stakable.saturating_add(staking_payout).saturating_add(rest);
+
+			apis::EraPayoutProjection { era, staking_payout, rest, total_stakable }
+		})
+		.collect()
+}
+
+/// Policy to cap the leftover of an era payout, which usually goes to the treasury.
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	codec::Encode,
+	codec::Decode,
+	codec::DecodeWithMemTracking,
+	codec::MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum CapRestPolicy {
+	/// The leftover is not capped.
+	#[default]
+	#[codec(index = 0)]
+	None,
+	/// If more than half of the stakable issuance is staked, the leftover is capped at the
+	/// staking payout scaled by the ratio of the unstaked to the staked issuance.
+	#[codec(index = 1)]
+	UnstakedRatio,
+	/// The leftover is capped at the given annual budget, scaled by the period of the era.
+	#[codec(index = 2)]
+	AnnualBudget(Balance),
+}
+
+impl CapRestPolicy {
+	/// Returns the leftover `rest` of an era payout capped according to this policy.
+	pub fn cap(
+		&self,
+		rest: Balance,
+		staking_payout: Balance,
+		total_staked: Balance,
+		total_stakable: Balance,
+		period_fraction: Perquintill,
+	) -> Balance {
+		match self {
+			Self::None => rest,
+			Self::UnstakedRatio => {
+				let other_issuance = total_stakable.saturating_sub(total_staked);
+				if total_staked > other_issuance {
+					let cap_rest =
+						Perquintill::from_rational(other_issuance, total_staked) * staking_payout;
+					rest.min(cap_rest)
+				} else {
+					rest
+				}
+			},
+			Self::AnnualBudget(budget) => rest.min(period_fraction * *budget),
 		}
 	}
 }
@@ -74,6 +324,8 @@ pub struct EraPayoutParams {
 	pub period_fraction: Perquintill,
 	/// Legacy auction proportion, which, if not `None`, is subtracted from `ideal_stake`.
 	pub legacy_auction_proportion: Option<Perquintill>,
+	/// Policy to cap the leftover that is not paid to stakers.
+	pub cap_rest: CapRestPolicy,
 }
 
 /// A specialized function to compute the inflation of the staking system, tailored for Polkadot
@@ -88,6 +340,7 @@ pub fn relay_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
 		falloff,
 		period_fraction,
 		legacy_auction_proportion,
+		cap_rest,
 	} = params;
 
 	let delta_annual_inflation = max_annual_inflation.saturating_sub(min_annual_inflation);
@@ -102,15 +355,143 @@ pub fn relay_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
 	let max_payout = period_fraction * max_annual_inflation * total_stakable;
 	let staking_payout = (period_fraction * staking_inflation) * total_stakable;
 	let rest = max_payout.saturating_sub(staking_payout);
+	let rest = cap_rest.cap(rest, staking_payout, total_staked, total_stakable, period_fraction);
 
-	let other_issuance = total_stakable.saturating_sub(total_staked);
-	if total_staked > other_issuance {
-		let _cap_rest = Perquintill::from_rational(other_issuance, total_staked) * staking_payout;
-		// We don't do anything with this, but if we wanted to, we could introduce a cap on the
-		// treasury amount with: `rest = rest.min(cap_rest);`
-	}
 	(staking_payout, rest)
 }
 
 // ---- TODO: Above is copy pasted from sdk, remove once we pull the version containing
 // https://github.com/paritytech/polkadot-sdk/pull/4938
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const TOTAL_STAKABLE: Balance = 1_000_000_000_000;
+
+	fn params(staked_percent: u64, cap_rest: CapRestPolicy) -> EraPayoutParams {
+		EraPayoutParams {
+			total_staked: Perquintill::from_percent(staked_percent) * TOTAL_STAKABLE,
+			total_stakable: TOTAL_STAKABLE,
+			ideal_stake: Perquintill::from_percent(75),
+			max_annual_inflation: Perquintill::from_percent(10),
+			min_annual_inflation: Perquintill::from_rational(25u64, 1000u64),
+			falloff: Perquintill::from_percent(5),
+			period_fraction: Perquintill::from_rational(1u64, 365u64),
+			legacy_auction_proportion: None,
+			cap_rest,
+		}
+	}
+
+	#[test]
+	fn no_cap_keeps_rest() {
+		for staked_percent in 0..=100 {
+			let (staking_payout, rest) =
+				relay_era_payout(params(staked_percent, CapRestPolicy::None));
+			let max_payout = Perquintill::from_rational(1u64, 365u64) *
+				Perquintill::from_percent(10) *
+				TOTAL_STAKABLE;
+
+			assert_eq!(rest, max_payout.saturating_sub(staking_payout));
+		}
+	}
+
+	#[test]
+	fn unstaked_ratio_cap_works() {
+		for staked_percent in 0..=100 {
+			let (staking_payout, uncapped) =
+				relay_era_payout(params(staked_percent, CapRestPolicy::None));
+			let (capped_staking_payout, rest) =
+				relay_era_payout(params(staked_percent, CapRestPolicy::UnstakedRatio));
+			assert_eq!(capped_staking_payout, staking_payout);
+
+			let total_staked = Perquintill::from_percent(staked_percent) * TOTAL_STAKABLE;
+			let other_issuance = TOTAL_STAKABLE - total_staked;
+			if total_staked > other_issuance {
+				let cap = Perquintill::from_rational(other_issuance, total_staked) * staking_payout;
+				assert_eq!(rest, uncapped.min(cap));
+			} else {
+				assert_eq!(rest, uncapped);
+			}
+		}
+
+		// Everything is staked, so nothing is left for the treasury.
+		assert_eq!(relay_era_payout(params(100, CapRestPolicy::UnstakedRatio)).1, 0);
+	}
+
+	#[test]
+	fn annual_budget_cap_works() {
+		let budget = 5_000_000_000;
+		let era_budget = Perquintill::from_rational(1u64, 365u64) * budget;
+
+		for staked_percent in 0..=100 {
+			let (staking_payout, uncapped) =
+				relay_era_payout(params(staked_percent, CapRestPolicy::None));
+			let (capped_staking_payout, rest) =
+				relay_era_payout(params(staked_percent, CapRestPolicy::AnnualBudget(budget)));
+
+			assert_eq!(capped_staking_payout, staking_payout);
+			assert_eq!(rest, uncapped.min(era_budget));
+		}
+
+		// The budget is only a cap, the leftover at the ideal stake is below it.
+		assert!(relay_era_payout(params(75, CapRestPolicy::None)).1 < era_budget);
+		// Without any stake, the whole leftover would be above it.
+		assert_eq!(relay_era_payout(params(0, CapRestPolicy::AnnualBudget(budget))).1, era_budget);
+	}
+
+	#[test]
+	fn project_era_payouts_works() {
+		let projection = project_era_payouts(7, 3, 1_000, |era, total_stakable| {
+			(total_stakable / 100, era as Balance)
+		});
+		let projected = |era, rest, total_stakable| apis::EraPayoutProjection {
+			era,
+			staking_payout: 10,
+			rest,
+			total_stakable,
+		};
+
+		assert_eq!(
+			projection,
+			vec![projected(7, 7, 1_017), projected(8, 8, 1_035), projected(9, 9, 1_054)]
+		);
+		assert_eq!(
+			project_era_payouts(0, u32::MAX, 0, |_, _| (0, 0)).len() as u32,
+			MAX_PROJECTED_ERAS
+		);
+	}
+
+	#[test]
+	fn era_payout_overrides_work() {
+		let overrides = apis::EraPayoutOverrides::default();
+		let mut overridden = params(60, CapRestPolicy::None);
+		assert!(overrides.is_empty());
+		overrides.apply(&mut overridden);
+		assert_eq!(relay_era_payout(overridden), relay_era_payout(params(60, CapRestPolicy::None)));
+
+		let overrides = apis::EraPayoutOverrides {
+			ideal_stake: Some(Perquintill::from_percent(60)),
+			..Default::default()
+		};
+		let mut overridden = params(60, CapRestPolicy::None);
+		assert!(!overrides.is_empty());
+		assert!(overrides.overrides_era_payout_params());
+		assert!(!overrides.overrides_fixed_emission());
+		overrides.apply(&mut overridden);
+		assert_eq!(overridden.ideal_stake, Perquintill::from_percent(60));
+		// Closer to the ideal stake, more goes to the stakers.
+		let (staking_payout, rest) = relay_era_payout(params(60, CapRestPolicy::None));
+		let (overridden_staking_payout, overridden_rest) = relay_era_payout(overridden);
+		assert!(overridden_staking_payout > staking_payout);
+		assert!(overridden_rest < rest);
+
+		let overrides = apis::EraPayoutOverrides {
+			annual_rate: Some(Perquintill::from_percent(5)),
+			..Default::default()
+		};
+		assert!(!overrides.is_empty());
+		assert!(!overrides.overrides_era_payout_params());
+		assert!(overrides.overrides_fixed_emission());
+	}
+}
@@ -0,0 +1,107 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers to implement [`ReferendaTracks`](crate::apis::ReferendaTracks) on top of the
+//! [`TracksInfo`] of a referenda pallet.
+
+use crate::apis::{ReferendaThresholds, ReferendaTrack};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use pallet_referenda::{Curve, Track, TracksInfo};
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill, Saturating};
+
+/// Returns all tracks of `T`.
+pub fn tracks<T, Balance, Moment>() -> Vec<ReferendaTrack<T::Id, Balance, Moment>>
+where
+	T: TracksInfo<Balance, Moment>,
+	Balance: Clone + 'static,
+	Moment: Clone + 'static,
+{
+	T::tracks()
+		.map(|track| {
+			let Track { id, info } = track.into_owned();
+			ReferendaTrack {
+				id,
+				name: String::from_utf8_lossy(&info.name).trim_end_matches('\0').into(),
+				max_deciding: info.max_deciding,
+				decision_deposit: info.decision_deposit,
+				prepare_period: info.prepare_period,
+				decision_period: info.decision_period,
+				confirm_period: info.confirm_period,
+				min_enactment_period: info.min_enactment_period,
+				min_approval: info.min_approval,
+				min_support: info.min_support,
+			}
+		})
+		.collect()
+}
+
+/// Returns the thresholds of the track `id` of `T` after the `elapsed` share of its decision
+/// period.
+pub fn thresholds<T, Balance, Moment>(id: T::Id, elapsed: Perbill) -> Option<ReferendaThresholds>
+where
+	T: TracksInfo<Balance, Moment>,
+	Balance: Clone + 'static,
+	Moment: Clone + 'static,
+{
+	let track = find_track::<T, _, _>(id)?;
+
+	Some(ReferendaThresholds {
+		min_approval: track.info.min_approval.threshold(elapsed),
+		min_support: track.info.min_support.threshold(elapsed),
+	})
+}
+
+/// Returns the time after the start of the decision period until a referendum on the track `id` of
+/// `T` with a constant `approval` and `support` is approved, including its confirmation.
+///
+/// This follows the estimation of the referenda pallet for the decision of a referendum.
+pub fn time_to_pass<T, Balance, Moment>(
+	id: T::Id,
+	approval: Perbill,
+	support: Perbill,
+) -> Option<Moment>
+where
+	T: TracksInfo<Balance, Moment>,
+	Balance: Clone + 'static,
+	Moment: AtLeast32BitUnsigned + Copy + 'static,
+{
+	let info = &find_track::<T, _, _>(id)?.info;
+
+	// `Curve::delay` is only an upper bound if the value is passing from the start.
+	let delay = |curve: &Curve, y| {
+		if curve.passing(Perbill::zero(), y) {
+			Perbill::zero()
+		} else {
+			curve.delay(y)
+		}
+	};
+	let elapsed = delay(&info.min_approval, approval).max(delay(&info.min_support, support));
+	if !info.min_approval.passing(elapsed, approval) || !info.min_support.passing(elapsed, support)
+	{
+		return None
+	}
+
+	Some(elapsed.mul_ceil(info.decision_period).saturating_add(info.confirm_period))
+}
+
+fn find_track<T, Balance, Moment>(id: T::Id) -> Option<Cow<'static, Track<T::Id, Balance, Moment>>>
+where
+	T: TracksInfo<Balance, Moment>,
+	Balance: Clone + 'static,
+	Moment: Clone + 'static,
+{
+	T::tracks().find(|track| track.id == id)
+}
@@ -143,8 +143,10 @@ pub mod system_parachain {
 pub const TREASURY_PALLET_ID: u8 = 18;
 
 pub mod proxy {
+	use crate::time::MILLISECS_PER_BLOCK;
+	use frame_support::traits::{ConstU64, Get};
 	use pallet_remote_proxy::ProxyDefinition;
-	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash};
+	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash, Moment};
 	use sp_runtime::traits::Convert;
 
 	/// The type used to represent the kinds of proxying allowed.
@@ -186,11 +188,26 @@ pub mod proxy {
 		Spokesperson,
 		#[codec(index = 10)]
 		ParaRegistration,
+		#[codec(index = 11)]
+		OnDemandOrdering,
 	}
 
 	/// Remote proxy interface that uses the relay chain as remote location.
-	pub struct RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>(
-		core::marker::PhantomData<(LocalProxyType, ProxyDefinitionConverter)>,
+	///
+	/// `ProxyDefinitionConverter` receives the relay chain proxy definition with its `delay`
+	/// already converted to local blocks. `LocalMillisecsPerBlock` is the block time of the block
+	/// number provider used by the local proxy pallet. It defaults to the relay chain block time,
+	/// which is correct for chains whose proxy pallet uses the relay chain block number.
+	pub struct RemoteProxyInterface<
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		LocalMillisecsPerBlock = ConstU64<MILLISECS_PER_BLOCK>,
+	>(
+		core::marker::PhantomData<(
+			LocalProxyType,
+			ProxyDefinitionConverter,
+			LocalMillisecsPerBlock,
+		)>,
 	);
 
 	impl<
@@ -199,8 +216,9 @@ pub mod proxy {
 				ProxyDefinition<AccountId, ProxyType, BlockNumber>,
 				Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>>,
 			>,
+			LocalMillisecsPerBlock: Get<Moment>,
 		> pallet_remote_proxy::RemoteProxyInterface<AccountId, LocalProxyType, BlockNumber>
-		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>
+		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, LocalMillisecsPerBlock>
 	{
 		type RemoteAccountId = AccountId;
 
@@ -222,6 +240,17 @@ pub mod proxy {
 			Some(local.clone())
 		}
 
+		fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber> {
+			let local_millisecs_per_block = LocalMillisecsPerBlock::get();
+			if local_millisecs_per_block == 0 {
+				return None;
+			}
+
+			let millisecs = Moment::from(delay).checked_mul(MILLISECS_PER_BLOCK)?;
+			// Round up to never shorten the delay.
+			millisecs.div_ceil(local_millisecs_per_block).try_into().ok()
+		}
+
 		fn remote_to_local_proxy_defintion(
 			remote: ProxyDefinition<
 				Self::RemoteAccountId,
@@ -229,13 +258,23 @@ pub mod proxy {
 				Self::RemoteBlockNumber,
 			>,
 		) -> Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>> {
-			ProxyDefinitionConverter::convert(remote)
+			let delay = Self::remote_to_local_delay(remote.delay)?;
+
+			ProxyDefinitionConverter::convert(ProxyDefinition { delay, ..remote })
 		}
 
 		#[cfg(feature = "runtime-benchmarks")]
 		fn create_remote_proxy_proof(
 			caller: &AccountId,
 			proxy: &AccountId,
+		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
+			Self::create_remote_proxy_batch_proof(caller, core::slice::from_ref(proxy))
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_remote_proxy_batch_proof(
+			caller: &AccountId,
+			proxies: &[AccountId],
 		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
 			use codec::Encode;
 			use sp_trie::TrieMut;
@@ -251,8 +290,10 @@ pub mod proxy {
 					delay: 0,
 				}];
 
-			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-				.unwrap();
+			for proxy in proxies {
+				trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+					.unwrap();
+			}
 			drop(trie);
 
 			(
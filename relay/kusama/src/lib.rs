@@ -23,6 +23,7 @@
 extern crate alloc;
 
 use alloc::{
+	boxed::Box,
 	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
 	vec,
 	vec::Vec,
@@ -50,8 +51,8 @@ use frame_support::{
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
 		ConstU32, ConstU8, ConstUint, Contains, Currency, EitherOf, EitherOfDiverse, EnsureOrigin,
 		EnsureOriginWithArg, EverythingBut, FromContains, InstanceFilter, KeyOwnerProofSystem,
-		LinearStoragePrice, OnUnbalanced, PrivilegeCmp, ProcessMessage, ProcessMessageError,
-		StorageMapShim, WithdrawReasons,
+		LinearStoragePrice, OnUnbalanced, PalletInfoAccess, PrivilegeCmp, ProcessMessage,
+		ProcessMessageError, StorageMapShim, WithdrawReasons,
 	},
 	weights::{
 		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
@@ -121,8 +122,8 @@ use sp_runtime::{
 		IdentityLookup, Keccak256, OpaqueKeys, SaturatedConversion, Saturating, Verify,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedU128, KeyTypeId, OpaqueValue, Perbill, Percent, Permill,
-	RuntimeDebug,
+	ApplyExtrinsicResult, DispatchError, FixedU128, KeyTypeId, OpaqueValue, Perbill, Percent,
+	Permill, RuntimeDebug,
 };
 use sp_staking::{EraIndex, SessionIndex};
 #[cfg(any(feature = "std", test))]
@@ -677,6 +678,9 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
+/// Where the burn portion of the treasury goes to.
+///
+/// The encoding of `None` and `Account` is the same as of an `Option<AccountId>`.
 #[derive(
 	Default,
 	MaxEncodedLen,
@@ -689,11 +693,25 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	PartialEq,
 	Debug,
 )]
-pub struct BurnDestinationAccount(pub Option<AccountId>);
+pub enum TreasuryBurnDestination {
+	/// Nothing is burned.
+	#[default]
+	#[codec(index = 0)]
+	None,
+	/// The burn portion is deposited into a local account.
+	#[codec(index = 1)]
+	Account(AccountId),
+	/// The burn portion is teleported to a location on a system parachain, e.g. an account on
+	/// Asset Hub.
+	///
+	/// If the teleport fails, the burn portion stays in the treasury.
+	#[codec(index = 2)]
+	Location(VersionedLocation),
+}
 
-impl BurnDestinationAccount {
+impl TreasuryBurnDestination {
 	pub fn is_set(&self) -> bool {
-		self.0.is_some()
+		!matches!(self, Self::None)
 	}
 }
 
@@ -733,6 +751,10 @@ pub mod dynamic_params {
 		/// generally be set to false.
 		#[codec(index = 4)]
 		pub static UseAuctionSlots: bool = true;
+
+		/// Policy to cap the leftover of era payouts that goes to the treasury.
+		#[codec(index = 5)]
+		pub static CapRest: relay_common::CapRestPolicy = relay_common::CapRestPolicy::None;
 	}
 
 	/// Parameters used by `pallet-treasury` to handle the burn process.
@@ -743,7 +765,7 @@ pub mod dynamic_params {
 		pub static BurnPortion: Permill = Permill::from_percent(0);
 
 		#[codec(index = 1)]
-		pub static BurnDestination: BurnDestinationAccount = Default::default();
+		pub static BurnDestination: TreasuryBurnDestination = Default::default();
 	}
 }
 
@@ -791,17 +813,18 @@ impl pallet_parameters::Config for Runtime {
 }
 
 pub struct EraPayout;
-impl pallet_staking::EraPayout<Balance> for EraPayout {
-	fn era_payout(
+impl EraPayout {
+	/// Returns the parameters of the payout of an era with the given stake and duration.
+	fn params(
 		total_staked: Balance,
-		_total_issuance: Balance,
+		total_stakable: Balance,
 		era_duration_millis: u64,
-	) -> (Balance, Balance) {
+	) -> relay_common::EraPayoutParams {
 		const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
 
-		let params = relay_common::EraPayoutParams {
+		relay_common::EraPayoutParams {
 			total_staked,
-			total_stakable: Nis::issuance().other,
+			total_stakable,
 			ideal_stake: dynamic_params::inflation::IdealStake::get(),
 			max_annual_inflation: dynamic_params::inflation::MaxInflation::get(),
 			min_annual_inflation: dynamic_params::inflation::MinInflation::get(),
@@ -818,7 +841,18 @@ impl pallet_staking::EraPayout<Balance> for EraPayout {
 			} else {
 				None
 			},
-		};
+			cap_rest: dynamic_params::inflation::CapRest::get(),
+		}
+	}
+}
+
+impl pallet_staking::EraPayout<Balance> for EraPayout {
+	fn era_payout(
+		total_staked: Balance,
+		_total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		let params = Self::params(total_staked, Nis::issuance().other, era_duration_millis);
 		log::debug!(target: "runtime::kusama", "params: {params:?}");
 		relay_common::relay_era_payout(params)
 	}
@@ -915,22 +949,89 @@ parameter_types! {
 	pub const MaxAuthorities: u32 = 100_000;
 	pub const MaxKeys: u32 = 10_000;
 	pub const MaxPeerInHeartbeats: u32 = 10_000;
+	// The teleport of the treasury burn is not part of the weight of the treasury's
+	// `on_initialize`, so it must stay small compared to the block.
+	pub MaxTreasuryBurnTeleportWeight: Weight =
+		Perbill::from_percent(5) * BlockWeights::get().max_block;
 }
 
 pub type BalancesNegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 pub struct TreasuryBurnHandler;
 
+impl TreasuryBurnHandler {
+	/// Teleport `amount` to `destination`.
+	///
+	/// The amount is deposited back into the treasury and teleported from there, so that it stays
+	/// in the treasury if the teleport fails. The treasury's `Deposit` and the XCM events record
+	/// what happened.
+	fn teleport(destination: VersionedLocation, amount: BalancesNegativeImbalance) {
+		use frame_support::{
+			dispatch::{DispatchClass, GetDispatchInfo},
+			traits::{Imbalance, UnfilteredDispatchable},
+		};
+
+		let value = amount.peek();
+		let treasury = TreasuryAccount::get();
+		<Treasury as OnUnbalanced<_>>::on_unbalanced(amount);
+
+		let result = Self::split_destination(&destination).and_then(|(dest, beneficiary)| {
+			let call = pallet_xcm::Call::<Runtime>::limited_teleport_assets {
+				dest: Box::new(dest.into()),
+				beneficiary: Box::new(beneficiary.into()),
+				assets: Box::new((Here, value).into()),
+				fee_asset_item: 0,
+				weight_limit: Unlimited,
+			};
+			// The burn happens in the `on_initialize` of the treasury, whose weight doesn't
+			// include the teleport.
+			let weight = call.get_dispatch_info().call_weight;
+			frame_support::ensure!(
+				weight.all_lte(MaxTreasuryBurnTeleportWeight::get()),
+				DispatchError::Other("The weight of the burn teleport exceeds its limit")
+			);
+			System::register_extra_weight_unchecked(weight, DispatchClass::Mandatory);
+
+			frame_support::storage::with_storage_layer(|| {
+				call.dispatch_bypass_filter(RuntimeOrigin::signed(treasury))
+					.map(|_| ())
+					.map_err(|e| e.error)
+			})
+		});
+
+		if let Err(error) = result {
+			log::warn!(
+				target: LOG_TARGET,
+				"Failed to teleport the treasury burn of {value} to {destination:?}: {error:?}",
+			);
+		}
+	}
+
+	/// Split `destination` into the parachain and the beneficiary on it.
+	fn split_destination(
+		destination: &VersionedLocation,
+	) -> Result<(Location, Location), DispatchError> {
+		let location = Location::try_from(destination.clone())
+			.map_err(|()| DispatchError::Other("Unsupported XCM version of burn destination"))?;
+
+		let (beneficiary, chain) = location.split_first_interior();
+		match chain {
+			Some(Parachain(id)) if beneficiary.parents == 0 && !beneficiary.is_here() =>
+				Ok((Location::new(0, Parachain(id)), beneficiary)),
+			_ => Err(DispatchError::Other("The burn destination is not a location on a parachain")),
+		}
+	}
+}
+
 impl OnUnbalanced<BalancesNegativeImbalance> for TreasuryBurnHandler {
 	fn on_nonzero_unbalanced(amount: BalancesNegativeImbalance) {
-		let destination = dynamic_params::treasury::BurnDestination::get();
-
-		if let BurnDestinationAccount(Some(account)) = destination {
+		match dynamic_params::treasury::BurnDestination::get() {
 			// Must resolve into existing but better to be safe.
-			Balances::resolve_creating(&account, amount);
-		} else {
-			// If no account to destinate the funds to, just drop the
-			// imbalance.
-			<() as OnUnbalanced<_>>::on_nonzero_unbalanced(amount)
+			TreasuryBurnDestination::Account(account) => {
+				Balances::resolve_creating(&account, amount);
+			},
+			TreasuryBurnDestination::Location(destination) => Self::teleport(destination, amount),
+			// If no account to destinate the funds to, just drop the imbalance.
+			TreasuryBurnDestination::None => <() as OnUnbalanced<_>>::on_nonzero_unbalanced(amount),
 		}
 	}
 }
@@ -1376,6 +1477,9 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType {
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			// Orders are placed with `OnDemandProxy::place_order`, which enforces the spending limit
+			// set by the proxied account.
+			ProxyType::OnDemandOrdering => false,
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1383,6 +1487,8 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			// On-demand orders spend funds.
+			(ProxyType::NonTransfer, ProxyType::OnDemandOrdering) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
@@ -1576,6 +1682,51 @@ impl parachains_on_demand::Config for Runtime {
 	type PalletId = OnDemandPalletId;
 }
 
+/// Places on-demand orders for [`pallet_on_demand_proxy`].
+pub struct OnDemandOrderPlacer;
+
+impl pallet_on_demand_proxy::PlaceOrder<AccountId, Balance> for OnDemandOrderPlacer {
+	type ParaId = ParaId;
+
+	fn place_order(
+		who: &AccountId,
+		max_amount: Balance,
+		para_id: ParaId,
+	) -> sp_runtime::DispatchResult {
+		use sp_runtime::traits::Dispatchable;
+
+		RuntimeCall::OnDemandAssignmentProvider(
+			parachains_on_demand::Call::place_order_keep_alive { max_amount, para_id },
+		)
+		.dispatch(RuntimeOrigin::signed(who.clone()))
+		.map(|_| ())
+		.map_err(|e| e.error)
+	}
+
+	fn place_order_weight() -> Weight {
+		use frame_support::dispatch::GetDispatchInfo;
+
+		parachains_on_demand::Call::<Runtime>::place_order_keep_alive {
+			max_amount: 0,
+			para_id: ParaId::new(0),
+		}
+		.get_dispatch_info()
+		.call_weight
+	}
+}
+
+parameter_types! {
+	pub const OnDemandOrderingProxyType: TransparentProxyType =
+		TransparentProxyType(ProxyType::OnDemandOrdering);
+}
+
+impl pallet_on_demand_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OrderPlacer = OnDemandOrderPlacer;
+	type OnDemandProxyType = OnDemandOrderingProxyType;
+	type WeightInfo = ();
+}
+
 impl parachains_assigner_coretime::Config for Runtime {}
 
 impl parachains_initializer::Config for Runtime {
@@ -1913,6 +2064,7 @@ construct_runtime! {
 		ParasSlashing: parachains_slashing = 63,
 		OnDemandAssignmentProvider: parachains_on_demand = 64,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 65,
+		OnDemandProxy: pallet_on_demand_proxy = 66,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -2296,13 +2448,101 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_inflation_projection(
+		eras: u32,
+		overrides: &EraPayoutOverrides,
+	) -> Vec<EraPayoutProjection> {
+		use pallet_staking::{ActiveEra, ErasTotalStake};
+		let (active_era, staked) = ActiveEra::<Runtime>::get()
+			.map(|ae| (ae.index, ErasTotalStake::<Runtime>::get(ae.index)))
+			.unwrap_or((0, 0));
+
+		// We assume un-delayed 6h eras.
+		let era_duration = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+		relay_common::project_era_payouts(
+			active_era,
+			eras,
+			Nis::issuance().other,
+			|_era, total_stakable| {
+				let mut params = EraPayout::params(staked, total_stakable, era_duration);
+				overrides.apply(&mut params);
+				relay_common::relay_era_payout(params)
+			},
+		)
+	}
 }
 
+/// The tracks of the referenda pallet.
+type ReferendaTracks = <Runtime as pallet_referenda::Config>::Tracks;
+/// The tracks of the fellowship referenda pallet.
+type FellowshipReferendaTracks =
+	<Runtime as pallet_referenda::Config<governance::FellowshipReferendaInstance>>::Tracks;
+
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_inflation_projection(eras: u32) -> Vec<EraPayoutProjection> {
+			Runtime::impl_experimental_inflation_projection(eras, &Default::default())
+		}
+
+		fn experimental_inflation_what_if(
+			overrides: EraPayoutOverrides,
+			eras: u32,
+		) -> Option<Vec<EraPayoutProjection>> {
+			// The era payout of Kusama does not have a fixed emission.
+			(!overrides.overrides_fixed_emission())
+				.then(|| Runtime::impl_experimental_inflation_projection(eras, &overrides))
+		}
+	}
+
+	impl relay_common::apis::ReferendaTracks<Block, u16, Balance, BlockNumber> for Runtime {
+		fn referenda_tracks(
+			pallet_index: u8,
+		) -> Option<Vec<relay_common::apis::ReferendaTrack<u16, Balance, BlockNumber>>> {
+			use relay_common::referenda::tracks;
+			match pallet_index {
+				i if i == Referenda::index() as u8 => Some(tracks::<ReferendaTracks, _, _>()),
+				i if i == FellowshipReferenda::index() as u8 =>
+					Some(tracks::<FellowshipReferendaTracks, _, _>()),
+				_ => None,
+			}
+		}
+
+		fn referenda_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<relay_common::apis::ReferendaThresholds> {
+			use relay_common::referenda::thresholds;
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					thresholds::<ReferendaTracks, _, _>(track, elapsed),
+				i if i == FellowshipReferenda::index() as u8 =>
+					thresholds::<FellowshipReferendaTracks, _, _>(track, elapsed),
+				_ => None,
+			}
+		}
+
+		fn referenda_time_to_pass(
+			pallet_index: u8,
+			track: u16,
+			approval: Perbill,
+			support: Perbill,
+		) -> Option<BlockNumber> {
+			use relay_common::referenda::time_to_pass;
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					time_to_pass::<ReferendaTracks, _, _>(track, approval, support),
+				i if i == FellowshipReferenda::index() as u8 =>
+					time_to_pass::<FellowshipReferendaTracks, _, _>(track, approval, support),
+				_ => None,
+			}
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -2857,6 +3097,30 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::trusted_query::TrustedQueryApi<Block> for Runtime {
+		fn is_trusted_reserve(asset: xcm::VersionedAsset, location: VersionedLocation) -> xcm_runtime_apis::trusted_query::XcmTrustedQueryResult {
+			XcmPallet::is_trusted_reserve(asset, location)
+		}
+		fn is_trusted_teleporter(asset: xcm::VersionedAsset, location: VersionedLocation) -> xcm_runtime_apis::trusted_query::XcmTrustedQueryResult {
+			XcmPallet::is_trusted_teleporter(asset, location)
+		}
+	}
+
+	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
+		fn authorized_aliasers(target: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
+			xcm_runtime_apis::authorized_aliases::Error
+		> {
+			XcmPallet::authorized_aliasers(target)
+		}
+		fn is_authorized_alias(origin: VersionedLocation, target: VersionedLocation) -> Result<
+			bool,
+			xcm_runtime_apis::authorized_aliases::Error
+		> {
+			XcmPallet::is_authorized_alias(origin, target)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -181,3 +181,273 @@ fn check_treasury_pallet_id() {
 		kusama_runtime_constants::TREASURY_PALLET_ID
 	);
 }
+
+#[test]
+fn treasury_burn_destination_encoding_is_compatible() {
+	let account = AccountId::from([1u8; 32]);
+
+	assert_eq!(TreasuryBurnDestination::None.encode(), None::<AccountId>.encode());
+	assert_eq!(
+		TreasuryBurnDestination::Account(account.clone()).encode(),
+		Some(account.clone()).encode()
+	);
+	assert_eq!(
+		TreasuryBurnDestination::decode(&mut &Some(account.clone()).encode()[..]),
+		Ok(TreasuryBurnDestination::Account(account))
+	);
+}
+
+/// The balance of the treasury account in [`treasury_burn_ext`].
+const TREASURY_BALANCE: Balance = 10 * GRAND;
+
+/// Asset Hub is reachable and the treasury can pay the delivery fees of a teleport to it.
+fn treasury_burn_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(TreasuryAccount::get(), TREASURY_BALANCE)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	parachains_configuration::GenesisConfig::<Runtime> {
+		config: parachains_configuration::HostConfiguration {
+			max_downward_message_size: 51_200,
+			..Default::default()
+		},
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_xcm::GenesisConfig::<Runtime> {
+		safe_xcm_version: Some(xcm::latest::VERSION),
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Dmp::make_parachain_reachable(1000);
+	});
+	ext
+}
+
+/// Set the burn destination and burn `amount` like the treasury does.
+fn burn_to(destination: TreasuryBurnDestination, amount: Balance) {
+	use frame_support::{assert_ok, traits::OnUnbalanced};
+
+	assert_ok!(Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Treasury(dynamic_params::treasury::Parameters::BurnDestination(
+			dynamic_params::treasury::BurnDestination,
+			Some(destination),
+		)),
+	));
+	TreasuryBurnHandler::on_unbalanced(Balances::issue(amount));
+}
+
+fn treasury_deposited(amount: Balance) -> bool {
+	System::events().iter().any(|record| {
+		record.event == RuntimeEvent::Treasury(pallet_treasury::Event::Deposit { value: amount })
+	})
+}
+
+fn xcm_sent() -> bool {
+	System::events().iter().any(|record| {
+		matches!(record.event, RuntimeEvent::XcmPallet(pallet_xcm::Event::Sent { .. }))
+	})
+}
+
+fn burn_location(chain: Junction) -> TreasuryBurnDestination {
+	let account = Junction::AccountId32 { network: None, id: [1; 32] };
+	TreasuryBurnDestination::Location(Location::new(0, [chain, account]).into())
+}
+
+#[test]
+fn treasury_burn_is_teleported() {
+	treasury_burn_ext().execute_with(|| {
+		let burn = 100 * GRAND;
+		burn_to(burn_location(Parachain(1000)), burn);
+
+		assert!(treasury_deposited(burn));
+		assert!(xcm_sent());
+		// The burn left the treasury, which also paid the delivery fees.
+		assert!(Balances::free_balance(TreasuryAccount::get()) < TREASURY_BALANCE);
+		assert_eq!(Balances::free_balance(xcm_config::CheckAccount::get()), burn);
+	});
+}
+
+#[test]
+fn failed_treasury_burn_teleport_stays_in_treasury() {
+	for destination in [
+		// The parachain is not reachable.
+		burn_location(Parachain(2000)),
+		// Not a location on a parachain.
+		burn_location(PalletInstance(1)),
+		TreasuryBurnDestination::Location(Location::here().into()),
+	] {
+		treasury_burn_ext().execute_with(|| {
+			let burn = 100 * GRAND;
+			burn_to(destination, burn);
+
+			// The changes of the teleport are rolled back.
+			assert!(treasury_deposited(burn));
+			assert!(!xcm_sent());
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), TREASURY_BALANCE + burn);
+			assert_eq!(Balances::free_balance(xcm_config::CheckAccount::get()), 0);
+		});
+	}
+}
+
+#[test]
+fn treasury_burn_falls_back_to_account_or_burn() {
+	treasury_burn_ext().execute_with(|| {
+		let account = AccountId::from([1; 32]);
+		let issuance = Balances::total_issuance();
+
+		burn_to(TreasuryBurnDestination::Account(account.clone()), 100 * GRAND);
+		assert_eq!(Balances::free_balance(&account), 100 * GRAND);
+		assert_eq!(Balances::total_issuance(), issuance + 100 * GRAND);
+
+		// Without a destination, the burn portion is burnt.
+		burn_to(TreasuryBurnDestination::None, 100 * GRAND);
+		assert_eq!(Balances::total_issuance(), issuance + 100 * GRAND);
+		assert!(!xcm_sent());
+	});
+}
+
+#[test]
+fn treasury_burn_teleport_weight_is_within_limit() {
+	let call = pallet_xcm::Call::<Runtime>::limited_teleport_assets {
+		dest: Box::new(Location::new(0, Parachain(1000)).into()),
+		beneficiary: Box::new(
+			Location::new(0, Junction::AccountId32 { network: None, id: [1; 32] }).into(),
+		),
+		assets: Box::new((Here, 100 * GRAND).into()),
+		fee_asset_item: 0,
+		weight_limit: Unlimited,
+	};
+
+	assert!(call
+		.get_dispatch_info()
+		.call_weight
+		.all_lte(MaxTreasuryBurnTeleportWeight::get()));
+}
+
+#[test]
+fn trusted_query_api_follows_xcm_config() {
+	use xcm_runtime_apis::trusted_query::runtime_decl_for_trusted_query_api::TrustedQueryApiV1;
+
+	let ksm = VersionedAsset::from(Asset::from((Here, 1_000 * UNITS)));
+	let asset_hub = VersionedLocation::from(xcm_config::AssetHubLocation::get());
+	let unknown_parachain = VersionedLocation::from(Location::new(0, Parachain(2000)));
+	let foreign_asset =
+		VersionedAsset::from(Asset::from((Location::new(0, Parachain(1000)), 1_000)));
+
+	// The system parachains are trusted teleporters of KSM.
+	assert_eq!(Runtime::is_trusted_teleporter(ksm.clone(), asset_hub.clone()), Ok(true));
+	assert_eq!(
+		Runtime::is_trusted_teleporter(ksm.clone(), xcm_config::Encointer::get().into()),
+		Ok(true)
+	);
+	assert_eq!(Runtime::is_trusted_teleporter(ksm.clone(), unknown_parachain), Ok(false));
+	assert_eq!(Runtime::is_trusted_teleporter(foreign_asset.clone(), asset_hub.clone()), Ok(false));
+
+	// The relay chain does not trust any reserve.
+	assert_eq!(Runtime::is_trusted_reserve(ksm, asset_hub.clone()), Ok(false));
+	assert_eq!(Runtime::is_trusted_reserve(foreign_asset, asset_hub), Ok(false));
+}
+
+#[test]
+fn authorized_aliasers_api_works() {
+	use xcm_runtime_apis::authorized_aliases::runtime_decl_for_authorized_aliasers_api::AuthorizedAliasersApiV1;
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let target = VersionedLocation::from(Location::new(
+			0,
+			AccountId32 { network: None, id: Charlie.to_account_id().into() },
+		));
+		let origin = VersionedLocation::from(Location::new(0, Parachain(1000)));
+
+		// Custom aliasing is disabled, so nobody is authorized to alias into local accounts.
+		assert_eq!(Runtime::authorized_aliasers(target.clone()), Ok(Vec::new()));
+		assert_eq!(Runtime::is_authorized_alias(origin, target), Ok(false));
+	});
+}
+
+#[test]
+fn referenda_tracks_api_works() {
+	use relay_common::apis::{
+		runtime_decl_for_referenda_tracks::ReferendaTracksV1, ReferendaThresholds,
+	};
+
+	let referenda = <Referenda as PalletInfoAccess>::index() as u8;
+	let fellowship = <FellowshipReferenda as PalletInfoAccess>::index() as u8;
+	let root = 0;
+
+	let tracks = Runtime::referenda_tracks(referenda).unwrap();
+	assert_eq!(tracks.len(), 16);
+	assert_eq!((tracks[0].id, tracks[0].name.as_str()), (root, "root"));
+	assert_eq!(tracks[0].confirm_period, 24 * HOURS);
+	assert!(!Runtime::referenda_tracks(fellowship).unwrap().is_empty());
+	assert_eq!(Runtime::referenda_tracks(<Balances as PalletInfoAccess>::index() as u8), None);
+
+	// The support of the root track decreases linearly from 50% to 0%.
+	let thresholds = |elapsed| Runtime::referenda_thresholds(referenda, root, elapsed).unwrap();
+	assert_eq!(thresholds(Perbill::zero()).min_support, Perbill::from_percent(50));
+	assert_eq!(thresholds(Perbill::from_percent(50)).min_support, Perbill::from_percent(25));
+	assert_eq!(
+		thresholds(Perbill::one()),
+		ReferendaThresholds {
+			min_approval: tracks[0].min_approval.threshold(Perbill::one()),
+			min_support: Perbill::zero(),
+		}
+	);
+	assert_eq!(Runtime::referenda_thresholds(referenda, 99, Perbill::zero()), None);
+
+	let time_to_pass = |approval, support| {
+		Runtime::referenda_time_to_pass(
+			referenda,
+			root,
+			Perbill::from_percent(approval),
+			Perbill::from_percent(support),
+		)
+	};
+	// A unanimous referendum only has to be confirmed.
+	assert_eq!(time_to_pass(100, 100), Some(24 * HOURS));
+	// Without support, the whole decision period has to pass.
+	assert_eq!(time_to_pass(100, 0), Some(14 * DAYS + 24 * HOURS));
+	// The approval never drops below 50%.
+	assert_eq!(time_to_pass(40, 100), None);
+}
+
+#[test]
+fn on_demand_ordering_proxy_orders_only_within_spending_limit() {
+	use pallet_on_demand_proxy::PlaceOrder;
+
+	let on_demand_ordering = TransparentProxyType(ProxyType::OnDemandOrdering);
+	let order = RuntimeCall::OnDemandAssignmentProvider(
+		parachains_on_demand::Call::place_order_keep_alive {
+			max_amount: UNITS,
+			para_id: 2000.into(),
+		},
+	);
+
+	// Orders through the proxy pallet would bypass the spending limit.
+	assert!(!on_demand_ordering.filter(&order));
+	assert!(!on_demand_ordering.filter(&RuntimeCall::Registrar(paras_registrar::Call::reserve {})));
+	assert_eq!(OnDemandOrderingProxyType::get(), on_demand_ordering);
+	assert!(
+		OnDemandOrderPlacer::place_order_weight().all_gte(order.get_dispatch_info().call_weight)
+	);
+
+	let non_transfer = TransparentProxyType(ProxyType::NonTransfer);
+	assert!(!non_transfer.is_superset(&on_demand_ordering));
+	assert!(!on_demand_ordering.is_superset(&non_transfer));
+	assert!(!non_transfer.filter(&RuntimeCall::OnDemandProxy(
+		pallet_on_demand_proxy::Call::set_spending_limit {
+			delegate: Charlie.to_account_id().into(),
+			limit: Some(UNITS),
+		}
+	)));
+}
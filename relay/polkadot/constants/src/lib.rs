@@ -38,6 +38,17 @@ pub mod currency {
 	pub const fn deposit(items: u32, bytes: u32) -> Balance {
 		items as Balance * 20 * DOLLARS + (bytes as Balance) * 100 * MILLICENTS
 	}
+
+	/// The base deposit for a recovery configuration.
+	///
+	/// One storage item; key size 32, value size 4 + 16 + 1 + 2 bytes without the friends.
+	pub const RECOVERY_CONFIG_DEPOSIT_BASE: Balance = deposit(1, 55);
+	/// The deposit per friend of a recovery configuration.
+	pub const RECOVERY_FRIEND_DEPOSIT_FACTOR: Balance = deposit(0, 32);
+	/// The deposit for initiating the recovery of an account.
+	///
+	/// One storage item; key size 64, value size 4 + 16 + 1 bytes without the vouching friends.
+	pub const RECOVERY_DEPOSIT: Balance = deposit(1, 85);
 }
 
 /// Time and blocks.
@@ -161,8 +172,10 @@ pub mod system_parachain {
 pub const TREASURY_PALLET_ID: u8 = 19;
 
 pub mod proxy {
+	use crate::time::MILLISECS_PER_BLOCK;
+	use frame_support::traits::{ConstU64, Get};
 	use pallet_remote_proxy::ProxyDefinition;
-	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash};
+	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash, Moment};
 	use sp_runtime::traits::Convert;
 
 	/// The type used to represent the kinds of proxying allowed.
@@ -193,11 +206,27 @@ pub mod proxy {
 		Auction = 7,
 		NominationPools = 8,
 		ParaRegistration = 9,
+		Spokesperson = 10,
+		ParaManagement = 11,
+		OnDemandOrdering = 12,
 	}
 
 	/// Remote proxy interface that uses the relay chain as remote location.
-	pub struct RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>(
-		core::marker::PhantomData<(LocalProxyType, ProxyDefinitionConverter)>,
+	///
+	/// `ProxyDefinitionConverter` receives the relay chain proxy definition with its `delay`
+	/// already converted to local blocks. `LocalMillisecsPerBlock` is the block time of the block
+	/// number provider used by the local proxy pallet. It defaults to the relay chain block time,
+	/// which is correct for chains whose proxy pallet uses the relay chain block number.
+	pub struct RemoteProxyInterface<
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		LocalMillisecsPerBlock = ConstU64<MILLISECS_PER_BLOCK>,
+	>(
+		core::marker::PhantomData<(
+			LocalProxyType,
+			ProxyDefinitionConverter,
+			LocalMillisecsPerBlock,
+		)>,
 	);
 
 	impl<
@@ -206,8 +235,9 @@ pub mod proxy {
 				ProxyDefinition<AccountId, ProxyType, BlockNumber>,
 				Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>>,
 			>,
+			LocalMillisecsPerBlock: Get<Moment>,
 		> pallet_remote_proxy::RemoteProxyInterface<AccountId, LocalProxyType, BlockNumber>
-		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>
+		for RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, LocalMillisecsPerBlock>
 	{
 		type RemoteAccountId = AccountId;
 
@@ -229,6 +259,17 @@ pub mod proxy {
 			Some(local.clone())
 		}
 
+		fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber> {
+			let local_millisecs_per_block = LocalMillisecsPerBlock::get();
+			if local_millisecs_per_block == 0 {
+				return None;
+			}
+
+			let millisecs = Moment::from(delay).checked_mul(MILLISECS_PER_BLOCK)?;
+			// Round up to never shorten the delay.
+			millisecs.div_ceil(local_millisecs_per_block).try_into().ok()
+		}
+
 		fn remote_to_local_proxy_defintion(
 			remote: ProxyDefinition<
 				Self::RemoteAccountId,
@@ -236,13 +277,23 @@ pub mod proxy {
 				Self::RemoteBlockNumber,
 			>,
 		) -> Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>> {
-			ProxyDefinitionConverter::convert(remote)
+			let delay = Self::remote_to_local_delay(remote.delay)?;
+
+			ProxyDefinitionConverter::convert(ProxyDefinition { delay, ..remote })
 		}
 
 		#[cfg(feature = "runtime-benchmarks")]
 		fn create_remote_proxy_proof(
 			caller: &AccountId,
 			proxy: &AccountId,
+		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
+			Self::create_remote_proxy_batch_proof(caller, core::slice::from_ref(proxy))
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_remote_proxy_batch_proof(
+			caller: &AccountId,
+			proxies: &[AccountId],
 		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
 			use codec::Encode;
 			use sp_trie::TrieMut;
@@ -258,8 +309,10 @@ pub mod proxy {
 					delay: 0,
 				}];
 
-			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-				.unwrap();
+			for proxy in proxies {
+				trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+					.unwrap();
+			}
 			drop(trie);
 
 			(
@@ -341,4 +394,25 @@ mod tests {
 		assert!(ProxyType::decode(&mut &OldProxyType::SudoBalances.encode()[..]).is_err());
 		assert!(ProxyType::decode(&mut &OldProxyType::IdentityJudgement.encode()[..]).is_err());
 	}
+
+	#[test]
+	fn proxy_type_encoding_is_stable() {
+		for (proxy_type, index) in [
+			(ProxyType::Any, 0u8),
+			(ProxyType::NonTransfer, 1),
+			(ProxyType::Governance, 2),
+			(ProxyType::Staking, 3),
+			(ProxyType::CancelProxy, 6),
+			(ProxyType::Auction, 7),
+			(ProxyType::NominationPools, 8),
+			(ProxyType::ParaRegistration, 9),
+			(ProxyType::Spokesperson, 10),
+			(ProxyType::ParaManagement, 11),
+			(ProxyType::OnDemandOrdering, 12),
+		] {
+			assert_eq!(proxy_type.encode(), vec![index]);
+			assert_eq!(ProxyType::decode(&mut &[index][..]), Ok(proxy_type));
+		}
+		assert!(ProxyType::decode(&mut &[13u8][..]).is_err());
+	}
 }
@@ -40,14 +40,15 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	construct_runtime,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstU32, ConstU8, ConstUint, EitherOf, EitherOfDiverse, Everything, FromContains, Get,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage,
-		ProcessMessageError, WithdrawReasons,
+		ConstU32, ConstU8, ConstUint, EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg,
+		Everything, FromContains, Get, InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice,
+		PalletInfoAccess, PrivilegeCmp, ProcessMessage, ProcessMessageError, WithdrawReasons,
 	},
 	weights::{
 		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
@@ -87,7 +88,7 @@ use polkadot_runtime_common::{
 	traits::OnSwap,
 	BlockHashCount, BlockLength, CurrencyToVote, SlowAdjustingFeeUpdate,
 };
-use relay_common::apis::InflationInfo;
+use relay_common::apis::{EraPayoutOverrides, EraPayoutProjection, InflationInfo};
 use runtime_parachains::{
 	assigner_coretime as parachains_assigner_coretime, configuration as parachains_configuration,
 	configuration::ActiveConfigHrmpChannelSizeAndCapacityRatio,
@@ -114,8 +115,8 @@ use sp_runtime::{
 		IdentityLookup, Keccak256, OpaqueKeys, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedU128, KeyTypeId, OpaqueValue, Perbill, Percent, Permill,
-	RuntimeDebug,
+	ApplyExtrinsicResult, BoundedVec, FixedU128, KeyTypeId, OpaqueValue, Perbill, Percent, Permill,
+	Perquintill, RuntimeDebug,
 };
 use sp_staking::{EraIndex, SessionIndex};
 #[cfg(any(feature = "std", test))]
@@ -662,30 +663,192 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
+/// A step of the [`InflationSchedule`].
+#[derive(
+	MaxEncodedLen,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	scale_info::TypeInfo,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+)]
+pub struct InflationStep {
+	/// The first era in which `annual_rate` is used.
+	pub from_era: EraIndex,
+	/// The yearly emission as a fraction of the base issuance.
+	pub annual_rate: Perquintill,
+}
+
+/// A stepped schedule of annual inflation rates.
+#[derive(
+	Default,
+	MaxEncodedLen,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	scale_info::TypeInfo,
+	Clone,
+	Eq,
+	PartialEq,
+	Debug,
+)]
+pub struct InflationSchedule(pub BoundedVec<InflationStep, ConstU32<8>>);
+
+impl InflationSchedule {
+	/// Returns the annual rate of the latest step that started at or before `era`.
+	pub fn annual_rate_at(&self, era: EraIndex) -> Option<Perquintill> {
+		self.0
+			.iter()
+			.filter(|step| step.from_era <= era)
+			.max_by_key(|step| step.from_era)
+			.map(|step| step.annual_rate)
+	}
+}
+
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters used to calculate era payouts, see [`EraPayout`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod inflation {
+		/// The fixed total issuance from which the yearly emission is calculated.
+		///
+		/// Defaults to the total issuance at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139),
+		/// block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
+		#[codec(index = 0)]
+		pub static BaseIssuance: Balance = 15_011_657_390_566_252_333;
+
+		/// The yearly emission as a fraction of `BaseIssuance`.
+		#[codec(index = 1)]
+		pub static AnnualRate: Perquintill = Perquintill::from_percent(8);
+
+		/// The fraction of every era emission that goes to the treasury.
+		#[codec(index = 2)]
+		pub static TreasuryShare: Perquintill = Perquintill::from_percent(15);
+
+		/// Stepped schedule of annual rates. If an era is covered by a step, the rate of the step
+		/// is used instead of `AnnualRate`.
+		#[codec(index = 3)]
+		pub static Schedule: InflationSchedule = Default::default();
+
+		/// Policy to cap the share of era payouts that goes to the treasury.
+		#[codec(index = 4)]
+		pub static CapRest: relay_common::CapRestPolicy = relay_common::CapRestPolicy::None;
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Inflation(dynamic_params::inflation::Parameters::AnnualRate(
+			dynamic_params::inflation::AnnualRate,
+			Some(Perquintill::from_percent(8)),
+		))
+	}
+}
+
+/// Defines what origin can modify which dynamic parameters.
+///
+/// The issuance and the annual rates define the monetary policy and require `Root`. How much of
+/// the emission goes to the treasury can also be changed by the `GeneralAdmin` track.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use crate::{
+			dynamic_params::inflation::ParametersKey as InflationKey, RuntimeParametersKey::*,
+		};
+
+		match key {
+			Inflation(InflationKey::BaseIssuance(_)) |
+			Inflation(InflationKey::AnnualRate(_)) |
+			Inflation(InflationKey::Schedule(_)) => frame_system::ensure_root(origin.clone()),
+			Inflation(InflationKey::TreasuryShare(_)) | Inflation(InflationKey::CapRest(_)) =>
+				EitherOf::<EnsureRoot<AccountId>, GeneralAdmin>::ensure_origin(origin.clone()),
+		}
+		.map_err(|_| origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
 /// Defines how much should the inflation be for an era given its duration.
+///
+/// The yearly emission is a fixed fraction of a fixed base issuance, as introduced by
+/// [Referendum 1139](https://polkadot.subsquare.io/referenda/1139). Both, as well as the treasury
+/// share and its cap, can be adjusted through [`dynamic_params::inflation`].
 pub struct EraPayout;
-impl pallet_staking::EraPayout<Balance> for EraPayout {
-	fn era_payout(
-		_total_staked: Balance,
-		_total_issuance: Balance,
+impl EraPayout {
+	/// Returns the payout of the era with index `era` and the given stake and duration.
+	///
+	/// The annual rate and the treasury share are replaced by the ones of `overrides`, if any.
+	fn payout_at(
+		era: EraIndex,
+		total_staked: Balance,
+		total_issuance: Balance,
 		era_duration_millis: u64,
+		overrides: &EraPayoutOverrides,
 	) -> (Balance, Balance) {
 		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
 		// A normal-sized era will have 1 / 365.25 here:
 		let relative_era_len =
 			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
 
-		// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139), block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
-		let fixed_total_issuance: i128 = 15_011_657_390_566_252_333;
-		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
-		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
+		let annual_rate = overrides.annual_rate.unwrap_or_else(|| {
+			dynamic_params::inflation::Schedule::get()
+				.annual_rate_at(era)
+				.unwrap_or_else(dynamic_params::inflation::AnnualRate::get)
+		});
+		let yearly_emission = FixedU128::from(annual_rate)
+			.saturating_mul_int(dynamic_params::inflation::BaseIssuance::get());
 
 		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
-		// 15% to treasury, as per ref 1139.
-		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
+		let treasury_share = overrides
+			.treasury_share
+			.unwrap_or_else(dynamic_params::inflation::TreasuryShare::get);
+		let to_treasury = FixedU128::from(treasury_share).saturating_mul_int(era_emission);
 		let to_stakers = era_emission.saturating_sub(to_treasury);
+		let to_treasury = dynamic_params::inflation::CapRest::get().cap(
+			to_treasury,
+			to_stakers,
+			total_staked,
+			total_issuance,
+			Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR),
+		);
 
-		(to_stakers.saturated_into(), to_treasury.saturated_into())
+		(to_stakers, to_treasury)
+	}
+}
+
+impl pallet_staking::EraPayout<Balance> for EraPayout {
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		let era = pallet_staking::ActiveEra::<Runtime>::get().map_or(0, |era| era.index);
+		Self::payout_at(era, total_staked, total_issuance, era_duration_millis, &Default::default())
 	}
 }
 
@@ -1037,6 +1200,25 @@ impl pallet_multisig::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+parameter_types! {
+	pub const ConfigDepositBase: Balance = RECOVERY_CONFIG_DEPOSIT_BASE;
+	pub const FriendDepositFactor: Balance = RECOVERY_FRIEND_DEPOSIT_FACTOR;
+	pub const MaxFriends: u16 = 9;
+	pub const RecoveryDeposit: Balance = RECOVERY_DEPOSIT;
+}
+
+impl pallet_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_recovery::WeightInfo<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ConfigDepositBase = ConfigDepositBase;
+	type FriendDepositFactor = FriendDepositFactor;
+	type MaxFriends = MaxFriends;
+	type RecoveryDeposit = RecoveryDeposit;
+	type BlockNumberProvider = System;
+}
+
 parameter_types! {
 	// One storage item; key size 32, value size 8; .
 	pub const ProxyDepositBase: Balance = deposit(1, 8);
@@ -1107,6 +1289,13 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType<ProxyType> {
 				RuntimeCall::Utility(..) |
 				RuntimeCall::Proxy(..) |
 				RuntimeCall::Multisig(..) |
+				RuntimeCall::Recovery(pallet_recovery::Call::as_recovered {..}) |
+				RuntimeCall::Recovery(pallet_recovery::Call::vouch_recovery {..}) |
+				RuntimeCall::Recovery(pallet_recovery::Call::claim_recovery {..}) |
+				RuntimeCall::Recovery(pallet_recovery::Call::close_recovery {..}) |
+				RuntimeCall::Recovery(pallet_recovery::Call::remove_recovery {..}) |
+				RuntimeCall::Recovery(pallet_recovery::Call::cancel_recovered {..}) |
+				// Specifically omitting Recovery `create_recovery`, `initiate_recovery`
 				RuntimeCall::Registrar(paras_registrar::Call::register {..}) |
 				RuntimeCall::Registrar(paras_registrar::Call::deregister {..}) |
 				// Specifically omitting Registrar `swap`
@@ -1166,6 +1355,40 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType<ProxyType> {
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			ProxyType::Spokesperson => matches!(
+				c,
+				RuntimeCall::System(frame_system::Call::remark { .. }) |
+					RuntimeCall::System(frame_system::Call::remark_with_event { .. })
+			),
+			ProxyType::ParaManagement => matches!(
+				c,
+				RuntimeCall::Registrar(paras_registrar::Call::reserve { .. }) |
+					RuntimeCall::Registrar(paras_registrar::Call::register { .. }) |
+					RuntimeCall::Registrar(paras_registrar::Call::deregister { .. }) |
+					// Specifically omitting Registrar `swap`
+					RuntimeCall::Registrar(paras_registrar::Call::add_lock { .. }) |
+					RuntimeCall::Registrar(paras_registrar::Call::schedule_code_upgrade { .. }) |
+					RuntimeCall::Registrar(paras_registrar::Call::set_current_head { .. }) |
+					RuntimeCall::Slots(slots::Call::trigger_onboard { .. }) |
+					RuntimeCall::OnDemand(
+						parachains_on_demand::Call::place_order_allow_death { .. }
+					) |
+					RuntimeCall::OnDemand(
+						parachains_on_demand::Call::place_order_keep_alive { .. }
+					) |
+					RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_init_open_channel { .. }) |
+					RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_accept_open_channel { .. }) |
+					RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_close_channel { .. }) |
+					RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_cancel_open_request { .. }) |
+					RuntimeCall::Hrmp(parachains_hrmp::Call::establish_channel_with_system { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
+					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
+			),
+			// Orders are placed with `OnDemandProxy::place_order`, which enforces the spending limit
+			// set by the proxied account.
+			ProxyType::OnDemandOrdering => false,
 		}
 	}
 
@@ -1174,7 +1397,14 @@ impl InstanceFilter<RuntimeCall> for TransparentProxyType<ProxyType> {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			// On-demand orders spend funds.
+			(ProxyType::NonTransfer, ProxyType::ParaManagement | ProxyType::OnDemandOrdering) =>
+				false,
 			(ProxyType::NonTransfer, _) => true,
+			(
+				ProxyType::ParaManagement,
+				ProxyType::ParaRegistration | ProxyType::OnDemandOrdering,
+			) => true,
 			_ => false,
 		}
 	}
@@ -1367,6 +1597,52 @@ impl parachains_on_demand::Config for Runtime {
 	type PalletId = OnDemandPalletId;
 }
 
+/// Places on-demand orders for [`pallet_on_demand_proxy`].
+pub struct OnDemandOrderPlacer;
+
+impl pallet_on_demand_proxy::PlaceOrder<AccountId, Balance> for OnDemandOrderPlacer {
+	type ParaId = ParaId;
+
+	fn place_order(
+		who: &AccountId,
+		max_amount: Balance,
+		para_id: ParaId,
+	) -> sp_runtime::DispatchResult {
+		use sp_runtime::traits::Dispatchable;
+
+		RuntimeCall::OnDemand(parachains_on_demand::Call::place_order_keep_alive {
+			max_amount,
+			para_id,
+		})
+		.dispatch(RuntimeOrigin::signed(who.clone()))
+		.map(|_| ())
+		.map_err(|e| e.error)
+	}
+
+	fn place_order_weight() -> Weight {
+		use frame_support::dispatch::GetDispatchInfo;
+
+		parachains_on_demand::Call::<Runtime>::place_order_keep_alive {
+			max_amount: 0,
+			para_id: ParaId::new(0),
+		}
+		.get_dispatch_info()
+		.call_weight
+	}
+}
+
+parameter_types! {
+	pub const OnDemandOrderingProxyType: TransparentProxyType =
+		TransparentProxyType(ProxyType::OnDemandOrdering);
+}
+
+impl pallet_on_demand_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OrderPlacer = OnDemandOrderPlacer;
+	type OnDemandProxyType = OnDemandOrderingProxyType;
+	type WeightInfo = ();
+}
+
 impl parachains_assigner_coretime::Config for Runtime {}
 
 impl parachains_initializer::Config for Runtime {
@@ -1614,6 +1890,7 @@ construct_runtime! {
 		Referenda: pallet_referenda = 21,
 		Origins: pallet_custom_origins = 22,
 		Whitelist: pallet_whitelist = 23,
+		Parameters: pallet_parameters = 46,
 
 		// Claims. Usable initially.
 		Claims: claims = 24,
@@ -1622,6 +1899,9 @@ construct_runtime! {
 		// Cunning utilities. Usable initially.
 		Utility: pallet_utility = 26,
 
+		// Social recovery module.
+		Recovery: pallet_recovery = 27,
+
 		// Identity: pallet_identity = 28, (removed post 1.2.8)
 
 		// Proxy module. Late addition.
@@ -1666,6 +1946,7 @@ construct_runtime! {
 		ParasSlashing: parachains_slashing = 63,
 		OnDemand: parachains_on_demand = 64,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 65,
+		OnDemandProxy: pallet_on_demand_proxy = 66,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -1789,8 +2070,10 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_recovery, Recovery]
 		[pallet_scheduler, Scheduler]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_staking, Staking]
@@ -2042,13 +2325,94 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_inflation_projection(
+		eras: u32,
+		overrides: &EraPayoutOverrides,
+	) -> Vec<EraPayoutProjection> {
+		use pallet_staking::{ActiveEra, ErasTotalStake};
+		let (active_era, staked) = ActiveEra::<Runtime>::get()
+			.map(|ae| (ae.index, ErasTotalStake::<Runtime>::get(ae.index)))
+			.unwrap_or((0, 0));
+
+		// We assume un-delayed 24h eras.
+		let era_duration = 24 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+		relay_common::project_era_payouts(
+			active_era,
+			eras,
+			Balances::total_issuance(),
+			|era, total_issuance| {
+				EraPayout::payout_at(era, staked, total_issuance, era_duration, overrides)
+			},
+		)
+	}
+
+	fn impl_experimental_inflation_what_if(
+		overrides: &EraPayoutOverrides,
+		eras: u32,
+	) -> Option<Vec<EraPayoutProjection>> {
+		// The fixed emission of Polkadot does not depend on the parameters of `EraPayoutParams`.
+		(!overrides.overrides_era_payout_params())
+			.then(|| Runtime::impl_experimental_inflation_projection(eras, overrides))
+	}
 }
 
+/// The tracks of the referenda pallet.
+type ReferendaTracks = <Runtime as pallet_referenda::Config>::Tracks;
+
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_inflation_projection(eras: u32) -> Vec<EraPayoutProjection> {
+			Runtime::impl_experimental_inflation_projection(eras, &Default::default())
+		}
+
+		fn experimental_inflation_what_if(
+			overrides: EraPayoutOverrides,
+			eras: u32,
+		) -> Option<Vec<EraPayoutProjection>> {
+			Runtime::impl_experimental_inflation_what_if(&overrides, eras)
+		}
+	}
+
+	impl relay_common::apis::ReferendaTracks<Block, u16, Balance, BlockNumber> for Runtime {
+		fn referenda_tracks(
+			pallet_index: u8,
+		) -> Option<Vec<relay_common::apis::ReferendaTrack<u16, Balance, BlockNumber>>> {
+			(pallet_index == Referenda::index() as u8)
+				.then(relay_common::referenda::tracks::<ReferendaTracks, _, _>)
+		}
+
+		fn referenda_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<relay_common::apis::ReferendaThresholds> {
+			(pallet_index == Referenda::index() as u8)
+				.then(|| {
+					relay_common::referenda::thresholds::<ReferendaTracks, _, _>(track, elapsed)
+				})
+				.flatten()
+		}
+
+		fn referenda_time_to_pass(
+			pallet_index: u8,
+			track: u16,
+			approval: Perbill,
+			support: Perbill,
+		) -> Option<BlockNumber> {
+			(pallet_index == Referenda::index() as u8)
+				.then(|| {
+					relay_common::referenda::time_to_pass::<ReferendaTracks, _, _>(
+						track, approval, support,
+					)
+				})
+				.flatten()
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -2662,6 +3026,30 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::trusted_query::TrustedQueryApi<Block> for Runtime {
+		fn is_trusted_reserve(asset: xcm::VersionedAsset, location: VersionedLocation) -> xcm_runtime_apis::trusted_query::XcmTrustedQueryResult {
+			XcmPallet::is_trusted_reserve(asset, location)
+		}
+		fn is_trusted_teleporter(asset: xcm::VersionedAsset, location: VersionedLocation) -> xcm_runtime_apis::trusted_query::XcmTrustedQueryResult {
+			XcmPallet::is_trusted_teleporter(asset, location)
+		}
+	}
+
+	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
+		fn authorized_aliasers(target: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
+			xcm_runtime_apis::authorized_aliases::Error
+		> {
+			XcmPallet::authorized_aliasers(target)
+		}
+		fn is_authorized_alias(origin: VersionedLocation, target: VersionedLocation) -> Result<
+			bool,
+			xcm_runtime_apis::authorized_aliases::Error
+		> {
+			XcmPallet::is_authorized_alias(origin, target)
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -2936,6 +3324,125 @@ mod test {
 		// Ensure that the name doesn't include `staging` (from the pallet name)
 		assert_eq!(vec!["xcm", "VersionedXcm"], path.segments);
 	}
+
+	#[test]
+	fn spokesperson_and_para_management_proxies_filter_calls() {
+		let spokesperson = TransparentProxyType(ProxyType::Spokesperson);
+		let para_management = TransparentProxyType(ProxyType::ParaManagement);
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+		let reserve = RuntimeCall::Registrar(paras_registrar::Call::reserve {});
+		let order = RuntimeCall::OnDemand(parachains_on_demand::Call::place_order_keep_alive {
+			max_amount: UNITS,
+			para_id: 2000.into(),
+		});
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: AccountId::from([1; 32]).into(),
+			value: UNITS,
+		});
+
+		assert!(spokesperson.filter(&remark));
+		assert!(!spokesperson.filter(&reserve));
+		assert!(!spokesperson.filter(&transfer));
+		assert!(para_management.filter(&reserve));
+		assert!(para_management.filter(&order));
+		assert!(!para_management.filter(&remark));
+		assert!(!para_management.filter(&transfer));
+		assert!(!para_management.filter(&RuntimeCall::Registrar(paras_registrar::Call::swap {
+			id: 2000.into(),
+			other: 2001.into(),
+		})));
+		assert!(!para_management.filter(&RuntimeCall::Utility(
+			pallet_utility::Call::as_derivative { index: 0, call: Box::new(reserve.clone()) }
+		)));
+		assert!(para_management.filter(&RuntimeCall::Utility(pallet_utility::Call::batch_all {
+			calls: vec![reserve.clone()]
+		})));
+
+		let non_transfer = TransparentProxyType(ProxyType::NonTransfer);
+		let para_registration = TransparentProxyType(ProxyType::ParaRegistration);
+		assert!(non_transfer.is_superset(&spokesperson));
+		assert!(!non_transfer.is_superset(&para_management));
+		assert!(para_management.is_superset(&para_registration));
+		assert!(!para_registration.is_superset(&para_management));
+		assert!(!spokesperson.is_superset(&para_management));
+	}
+
+	#[test]
+	fn on_demand_ordering_proxy_orders_only_within_spending_limit() {
+		use frame_support::dispatch::GetDispatchInfo;
+		use pallet_on_demand_proxy::PlaceOrder;
+
+		let on_demand_ordering = TransparentProxyType(ProxyType::OnDemandOrdering);
+		let order = RuntimeCall::OnDemand(parachains_on_demand::Call::place_order_keep_alive {
+			max_amount: UNITS,
+			para_id: 2000.into(),
+		});
+
+		// Orders through the proxy pallet would bypass the spending limit.
+		assert!(!on_demand_ordering.filter(&order));
+		assert!(
+			!on_demand_ordering.filter(&RuntimeCall::Registrar(paras_registrar::Call::reserve {}))
+		);
+		assert_eq!(OnDemandOrderingProxyType::get(), on_demand_ordering);
+		assert!(OnDemandOrderPlacer::place_order_weight()
+			.all_gte(order.get_dispatch_info().call_weight));
+
+		let para_management = TransparentProxyType(ProxyType::ParaManagement);
+		let non_transfer = TransparentProxyType(ProxyType::NonTransfer);
+		assert!(para_management.is_superset(&on_demand_ordering));
+		assert!(!non_transfer.is_superset(&on_demand_ordering));
+		assert!(!on_demand_ordering.is_superset(&para_management));
+		assert!(!non_transfer.filter(&RuntimeCall::OnDemandProxy(
+			pallet_on_demand_proxy::Call::set_spending_limit {
+				delegate: AccountId::from([1; 32]).into(),
+				limit: Some(UNITS),
+			}
+		)));
+	}
+
+	#[test]
+	fn referenda_tracks_api_works() {
+		use relay_common::apis::{
+			runtime_decl_for_referenda_tracks::ReferendaTracksV1, ReferendaThresholds,
+		};
+
+		let referenda = Referenda::index() as u8;
+		let root = 0;
+
+		let tracks = Runtime::referenda_tracks(referenda).unwrap();
+		assert_eq!(tracks.len(), 16);
+		assert_eq!((tracks[0].id, tracks[0].name.as_str()), (root, "root"));
+		assert_eq!(tracks[0].decision_period, 28 * DAYS);
+		assert_eq!(Runtime::referenda_tracks(Balances::index() as u8), None);
+
+		for elapsed in [Perbill::zero(), Perbill::from_percent(50), Perbill::one()] {
+			assert_eq!(
+				Runtime::referenda_thresholds(referenda, root, elapsed),
+				Some(ReferendaThresholds {
+					min_approval: tracks[0].min_approval.threshold(elapsed),
+					min_support: tracks[0].min_support.threshold(elapsed),
+				})
+			);
+		}
+		assert_eq!(Runtime::referenda_thresholds(referenda, 99, Perbill::zero()), None);
+		assert_eq!(
+			Runtime::referenda_thresholds(Balances::index() as u8, root, Perbill::zero()),
+			None
+		);
+
+		let time_to_pass = |approval, support| {
+			Runtime::referenda_time_to_pass(
+				referenda,
+				root,
+				Perbill::from_percent(approval),
+				Perbill::from_percent(support),
+			)
+		};
+		// A unanimous referendum only has to be confirmed.
+		assert_eq!(time_to_pass(100, 100), Some(24 * HOURS));
+		// The approval never drops below 50%.
+		assert_eq!(time_to_pass(40, 100), None);
+	}
 }
 
 #[cfg(test)]
@@ -2977,100 +3484,87 @@ mod multiplier_tests {
 		})
 	}
 
-	use approx::assert_relative_eq;
 	const MILLISECONDS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
 
 	#[test]
 	fn staking_inflation_correct_single_era() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123, // ignored
-			456, // ignored
-			MILLISECONDS_PER_DAY,
-		);
+		let (to_stakers, to_treasury) = sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(
+				123, // ignored
+				456, // ignored
+				MILLISECONDS_PER_DAY,
+			)
+		});
 
-		// Values are within 0.1%
-		assert_relative_eq!(to_stakers as f64, (279_477 * UNITS) as f64, max_relative = 0.001);
-		assert_relative_eq!(to_treasury as f64, (49_320 * UNITS) as f64, max_relative = 0.001);
+		// About 279,477 DOT to the stakers and 49,320 DOT to the treasury.
+		assert_eq!(to_stakers, 2_794_778_104_198_508);
+		assert_eq!(to_treasury, 493_196_136_035_030);
 		// Total per day is ~328,797 DOT
-		assert_relative_eq!(
-			(to_stakers as f64 + to_treasury as f64),
-			(328_797 * UNITS) as f64,
-			max_relative = 0.001
-		);
+		assert_eq!(to_stakers + to_treasury, 3_287_974_240_233_538);
 	}
 
 	#[test]
 	fn staking_inflation_correct_longer_era() {
-		// Twice the era duration means twice the emission:
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123, // ignored
-			456, // ignored
-			2 * MILLISECONDS_PER_DAY,
-		);
+		// Twice the era duration means twice the emission, up to rounding:
+		let (to_stakers, to_treasury) = sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(
+				123, // ignored
+				456, // ignored
+				2 * MILLISECONDS_PER_DAY,
+			)
+		});
 
-		assert_relative_eq!(
-			to_stakers as f64,
-			(279_477 * UNITS) as f64 * 2.0,
-			max_relative = 0.001
-		);
-		assert_relative_eq!(
-			to_treasury as f64,
-			(49_320 * UNITS) as f64 * 2.0,
-			max_relative = 0.001
-		);
+		assert_eq!(to_stakers, 5_589_556_208_397_017);
+		assert_eq!(to_treasury, 986_392_272_070_061);
 	}
 
 	#[test]
 	fn staking_inflation_correct_whole_year() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                  // ignored
-			456,                                  // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
-		);
+		let (to_stakers, to_treasury) = sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(
+				123,                                  // ignored
+				456,                                  // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
+			)
+		});
 
-		// Our yearly emissions is about 120M DOT:
-		let yearly_emission = 120_093_259 * UNITS;
-		assert_relative_eq!(
-			to_stakers as f64 + to_treasury as f64,
-			yearly_emission as f64,
-			max_relative = 0.001
-		);
+		// Our yearly emissions is about 120M DOT, 8% of the base issuance:
+		let yearly_emission = 1_200_932_591_245_300_186;
+		assert_eq!(yearly_emission, 15_011_657_390_566_252_333 * 8 / 100);
+		assert_eq!(to_stakers + to_treasury, yearly_emission);
 
-		assert_relative_eq!(to_stakers as f64, yearly_emission as f64 * 0.85, max_relative = 0.001);
-		assert_relative_eq!(
-			to_treasury as f64,
-			yearly_emission as f64 * 0.15,
-			max_relative = 0.001
-		);
+		// 85% to the stakers and 15% to the treasury.
+		assert_eq!(to_stakers, 1_020_792_702_558_505_159);
+		assert_eq!(to_treasury, 180_139_888_686_795_027);
 	}
 
 	// 10 years into the future, our values do not overflow.
 	#[test]
 	fn staking_inflation_correct_not_overflow() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                 // ignored
-			456,                                 // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
-		);
+		let (to_stakers, to_treasury) = sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(
+				123,                                 // ignored
+				456,                                 // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
+			)
+		});
 		let initial_ti: i128 = 15_011_657_390_566_252_333;
 		let projected_total_issuance = (to_stakers as i128 + to_treasury as i128) + initial_ti;
 
 		// In 2034, there will be about 2.7 billion DOT in existence.
-		assert_relative_eq!(
-			projected_total_issuance as f64,
-			(2_700_000_000 * UNITS) as f64,
-			max_relative = 0.001
-		);
+		assert_eq!(projected_total_issuance, 27_020_983_303_019_254_193);
 	}
 
 	// Print percent per year, just as convenience.
 	#[test]
 	fn staking_inflation_correct_print_percent() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                  // ignored
-			456,                                  // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
-		);
+		let (to_stakers, to_treasury) = sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(
+				123,                                  // ignored
+				456,                                  // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
+			)
+		});
 		let yearly_emission = to_stakers + to_treasury;
 		let mut ti: i128 = 15_011_657_390_566_252_333;
 
@@ -3084,6 +3578,201 @@ mod multiplier_tests {
 		}
 	}
 
+	/// The era payout of Referendum 1139 as it was hard-coded before the inflation parameters
+	/// became dynamic.
+	fn ref_1139_era_payout(era_duration_millis: u64) -> (Balance, Balance) {
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+		let relative_era_len =
+			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+
+		let fixed_total_issuance: i128 = 15_011_657_390_566_252_333;
+		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
+		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
+
+		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
+		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
+		let to_stakers = era_emission.saturating_sub(to_treasury);
+
+		(to_stakers.saturated_into(), to_treasury.saturated_into())
+	}
+
+	#[test]
+	fn staking_inflation_default_parameters_match_ref_1139() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for era_duration_millis in [
+				0,
+				1,
+				MILLISECONDS_PER_DAY / 4,
+				MILLISECONDS_PER_DAY,
+				MILLISECONDS_PER_DAY + 12_345,
+				2 * MILLISECONDS_PER_DAY,
+				(36525 * MILLISECONDS_PER_DAY) / 100,
+				(36525 * MILLISECONDS_PER_DAY) / 10,
+			] {
+				assert_eq!(
+					super::EraPayout::era_payout(123, 456, era_duration_millis),
+					ref_1139_era_payout(era_duration_millis)
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn staking_inflation_follows_parameters() {
+		use dynamic_params::inflation;
+		use frame_support::assert_ok;
+
+		const ONE_YEAR: u64 = (36525 * MILLISECONDS_PER_DAY) / 100;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let set_parameter = |parameter| {
+				assert_ok!(Parameters::set_parameter(
+					RuntimeOrigin::root(),
+					RuntimeParameters::Inflation(parameter)
+				));
+			};
+
+			set_parameter(inflation::Parameters::BaseIssuance(
+				inflation::BaseIssuance,
+				Some(1_000_000 * UNITS),
+			));
+			set_parameter(inflation::Parameters::AnnualRate(
+				inflation::AnnualRate,
+				Some(Perquintill::from_percent(10)),
+			));
+			set_parameter(inflation::Parameters::TreasuryShare(
+				inflation::TreasuryShare,
+				Some(Perquintill::from_percent(20)),
+			));
+			assert_eq!(
+				super::EraPayout::era_payout(123, 456, ONE_YEAR),
+				(80_000 * UNITS, 20_000 * UNITS)
+			);
+
+			// The schedule overrides the annual rate from the era of its first step on.
+			let step = |from_era, percent| InflationStep {
+				from_era,
+				annual_rate: Perquintill::from_percent(percent),
+			};
+			set_parameter(inflation::Parameters::Schedule(
+				inflation::Schedule,
+				Some(InflationSchedule(vec![step(10, 4), step(5, 5)].try_into().unwrap())),
+			));
+			assert_eq!(
+				super::EraPayout::era_payout(123, 456, ONE_YEAR),
+				(80_000 * UNITS, 20_000 * UNITS)
+			);
+
+			pallet_staking::ActiveEra::<Runtime>::put(pallet_staking::ActiveEraInfo {
+				index: 5,
+				start: None,
+			});
+			assert_eq!(
+				super::EraPayout::era_payout(123, 456, ONE_YEAR),
+				(40_000 * UNITS, 10_000 * UNITS)
+			);
+
+			pallet_staking::ActiveEra::<Runtime>::put(pallet_staking::ActiveEraInfo {
+				index: 12,
+				start: None,
+			});
+			assert_eq!(
+				super::EraPayout::era_payout(123, 456, ONE_YEAR),
+				(32_000 * UNITS, 8_000 * UNITS)
+			);
+
+			// The treasury share is capped, the stakers are not affected.
+			set_parameter(inflation::Parameters::CapRest(
+				inflation::CapRest,
+				Some(relay_common::CapRestPolicy::AnnualBudget(3_000 * UNITS)),
+			));
+			assert_eq!(
+				super::EraPayout::era_payout(123, 456, ONE_YEAR),
+				(32_000 * UNITS, 3_000 * UNITS)
+			);
+		});
+	}
+
+	#[test]
+	fn inflation_what_if_applies_overrides() {
+		use relay_common::apis::EraPayoutProjection;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let projected = |era, staking_payout, rest, total_stakable| EraPayoutProjection {
+				era,
+				staking_payout,
+				rest,
+				total_stakable,
+			};
+
+			assert_eq!(
+				Runtime::impl_experimental_inflation_what_if(&Default::default(), 2),
+				Some(vec![
+					projected(0, 2_794_778_104_198_508, 493_196_136_035_030, 3_287_974_240_233_538),
+					projected(1, 2_794_778_104_198_508, 493_196_136_035_030, 6_575_948_480_467_076),
+				])
+			);
+
+			// Half the annual rate and a larger treasury share.
+			let overrides = EraPayoutOverrides {
+				annual_rate: Some(Perquintill::from_percent(4)),
+				treasury_share: Some(Perquintill::from_percent(20)),
+				..Default::default()
+			};
+			assert_eq!(
+				Runtime::impl_experimental_inflation_what_if(&overrides, 2),
+				Some(vec![
+					projected(0, 1_315_189_696_093_416, 328_797_424_023_353, 1_643_987_120_116_769),
+					projected(1, 1_315_189_696_093_416, 328_797_424_023_353, 3_287_974_240_233_538),
+				])
+			);
+			// The projection of the current parameters is not affected.
+			assert_eq!(
+				Runtime::impl_experimental_inflation_projection(1, &Default::default())[0].rest,
+				493_196_136_035_030
+			);
+
+			// The fixed emission does not depend on the parameters of `EraPayoutParams`.
+			let overrides = EraPayoutOverrides {
+				ideal_stake: Some(Perquintill::from_percent(60)),
+				..Default::default()
+			};
+			assert_eq!(Runtime::impl_experimental_inflation_what_if(&overrides, 2), None);
+		});
+	}
+
+	#[test]
+	fn inflation_parameters_require_matching_origins() {
+		use dynamic_params::inflation;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let general_admin: RuntimeOrigin = pallet_custom_origins::Origin::GeneralAdmin.into();
+			let annual_rate = RuntimeParameters::Inflation(inflation::Parameters::AnnualRate(
+				inflation::AnnualRate,
+				Some(Perquintill::from_percent(100)),
+			));
+			let treasury_share =
+				RuntimeParameters::Inflation(inflation::Parameters::TreasuryShare(
+					inflation::TreasuryShare,
+					Some(Perquintill::from_percent(20)),
+				));
+
+			assert!(Parameters::set_parameter(
+				RuntimeOrigin::signed(AccountId::from([0; 32])),
+				treasury_share.clone(),
+			)
+			.is_err());
+
+			// The monetary policy requires root.
+			assert!(Parameters::set_parameter(general_admin.clone(), annual_rate.clone()).is_err());
+			assert!(Parameters::set_parameter(RuntimeOrigin::root(), annual_rate).is_ok());
+
+			// The treasury share can also be set by the general admin.
+			assert!(Parameters::set_parameter(general_admin, treasury_share).is_ok());
+			assert_eq!(inflation::TreasuryShare::get(), Perquintill::from_percent(20));
+		});
+	}
+
 	#[test]
 	fn fast_unstake_estimate() {
 		use pallet_fast_unstake::WeightInfo;
@@ -3186,33 +3875,111 @@ mod multiplier_tests {
 }
 #[cfg(all(test, feature = "try-runtime"))]
 mod remote_tests {
+	//! Tests against the live state of Polkadot.
+	//!
+	//! The state is downloaded from `WS`. If `SNAP` is set, the state is stored at the given path
+	//! and later runs use it without touching the network, which makes them reproducible. Tests
+	//! that only need a part of the state store it next to `SNAP`, e.g. `next_inflation` at
+	//! `<SNAP>.next_inflation`, to not mix it up with the full state. The checks record their
+	//! outcomes next to the state snapshot and compare them on later runs, e.g. with another
+	//! runtime version.
+	//!
+	//! To record the outcomes against the current runtime and check a new runtime against them:
+	//!
+	//! ```sh
+	//! # Download the state and record the outcomes.
+	//! SNAP=polkadot.snap RUN_OPENGOV_TEST=1 RUN_MIGRATION_TESTS=1 \
+	//!     cargo test -p polkadot-runtime --features try-runtime remote_tests
+	//! # Check the changed runtime offline against the same state and the recorded outcomes.
+	//! SNAP=polkadot.snap cargo test -p polkadot-runtime --features try-runtime remote_tests
+	//! ```
+	//!
+	//! To record the outcomes again, e.g. after an intended change, delete the `.expected` files
+	//! next to the state snapshot.
 	use super::*;
 	use frame_try_runtime::{runtime_decl_for_try_runtime::TryRuntime, UpgradeCheckSelect};
 	use remote_externalities::{
-		Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig, Transport,
+		Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig,
 	};
 	use std::env::var;
 
+	/// The default endpoint to download the state from.
+	const DEFAULT_URI: &str = "wss://polkadot-rpc.dwellir.com";
+
+	/// Returns the snapshot of the full state given by `SNAP`, if any.
+	fn state_snapshot() -> Option<SnapshotConfig> {
+		var("SNAP").map(|s| s.into()).ok()
+	}
+
+	/// Returns the snapshot of the partial state used by the test `name`, if `SNAP` is given.
+	fn partial_state_snapshot(name: &str) -> Option<SnapshotConfig> {
+		var("SNAP").map(|s| format!("{s}.{name}").into()).ok()
+	}
+
+	/// Returns `true` if the tests run offline from an existing state snapshot.
+	fn is_offline() -> bool {
+		state_snapshot().is_some_and(|snapshot| snapshot.path.exists())
+	}
+
+	/// Returns `true` if the test gated behind `env` should run.
+	///
+	/// Tests always run offline from an existing state snapshot, but only online if `env` is set.
+	fn should_run(env: &str) -> bool {
+		is_offline() || var(env).is_ok()
+	}
+
 	async fn remote_ext_test_setup() -> RemoteExternalities<Block> {
-		let transport: Transport =
-			var("WS").unwrap_or("wss://polkadot-rpc.dwellir.com".to_string()).into();
-		let maybe_state_snapshot: Option<SnapshotConfig> = var("SNAP").map(|s| s.into()).ok();
-		Builder::<Block>::default()
-			.mode(if let Some(state_snapshot) = maybe_state_snapshot {
-				Mode::OfflineOrElseOnline(
-					OfflineConfig { state_snapshot: state_snapshot.clone() },
-					OnlineConfig {
-						transport,
-						state_snapshot: Some(state_snapshot),
-						..Default::default()
-					},
-				)
-			} else {
-				Mode::Online(OnlineConfig { transport, ..Default::default() })
-			})
-			.build()
-			.await
-			.unwrap()
+		remote_ext_with(DEFAULT_URI, OnlineConfig::default(), state_snapshot()).await
+	}
+
+	/// Build the externalities from the existing `snapshot` without touching the network.
+	///
+	/// Otherwise the state given by `online` is downloaded from `WS` or `default_uri` and stored at
+	/// `snapshot`, if given, to run the same checks offline later on.
+	async fn remote_ext_with(
+		default_uri: &str,
+		online: OnlineConfig<Block>,
+		snapshot: Option<SnapshotConfig>,
+	) -> RemoteExternalities<Block> {
+		let mode = match snapshot {
+			Some(state_snapshot) if state_snapshot.path.exists() =>
+				Mode::Offline(OfflineConfig { state_snapshot }),
+			state_snapshot => Mode::Online(OnlineConfig {
+				transport: var("WS").unwrap_or(default_uri.to_string()).into(),
+				state_snapshot,
+				..online
+			}),
+		};
+
+		Builder::<Block>::default().mode(mode).build().await.unwrap()
+	}
+
+	/// Compare the `outcomes` of `check` with the ones recorded by a previous run against the same
+	/// state `snapshot`, or record them if there are none yet.
+	///
+	/// The outcomes are recorded next to the state snapshot, which allows to compare them across
+	/// runtime versions. Nothing is recorded without a state snapshot.
+	fn check_recorded_outcomes(
+		snapshot: Option<SnapshotConfig>,
+		check: &str,
+		outcomes: Vec<String>,
+	) {
+		let Some(snapshot) = snapshot else { return };
+		let path = snapshot.path.with_extension(format!("{check}.expected"));
+		let outcomes = outcomes.join("\n");
+
+		match std::fs::read_to_string(&path) {
+			Ok(expected) => assert_eq!(
+				outcomes,
+				expected.trim_end(),
+				"outcomes of `{check}` differ from the ones recorded at {}",
+				path.display()
+			),
+			Err(_) => {
+				log::info!(target: LOG_TARGET, "recording outcomes of `{check}` at {}", path.display());
+				std::fs::write(&path, outcomes + "\n").expect("failed to record outcomes");
+			},
+		}
 	}
 
 	#[tokio::test]
@@ -3240,7 +4007,7 @@ mod remote_tests {
 
 	#[tokio::test]
 	async fn dispatch_all_proposals() {
-		if var("RUN_OPENGOV_TEST").is_err() {
+		if !should_run("RUN_OPENGOV_TEST") {
 			return;
 		}
 
@@ -3249,8 +4016,9 @@ mod remote_tests {
 		ext.execute_with(|| {
 			type Ref = pallet_referenda::ReferendumInfoOf<Runtime, ()>;
 			type RefStatus = pallet_referenda::ReferendumStatusOf<Runtime, ()>;
+			use frame_support::storage::{with_transaction, TransactionOutcome};
 			use sp_runtime::traits::Dispatchable;
-			let all_refs: Vec<(u32, RefStatus)> =
+			let mut all_refs: Vec<(u32, RefStatus)> =
 				pallet_referenda::ReferendumInfoFor::<Runtime>::iter()
 					.filter_map(|(idx, reff): (_, Ref)| {
 						if let Ref::Ongoing(ref_status) = reff {
@@ -3260,7 +4028,9 @@ mod remote_tests {
 						}
 					})
 					.collect::<Vec<_>>();
+			all_refs.sort_by_key(|(idx, _)| *idx);
 
+			let mut outcomes = Vec::new();
 			for (ref_index, referenda) in all_refs {
 				log::info!(target: LOG_TARGET, "🚀 executing referenda #{ref_index}");
 				let RefStatus { origin, proposal, .. } = referenda;
@@ -3274,25 +4044,45 @@ mod remote_tests {
 					Ok(x) => x,
 					Err(e) => {
 						log::error!(target: LOG_TARGET, "failed to get preimage: {e:?}");
+						outcomes.push(format!("#{ref_index}: missing preimage"));
 						continue;
 					}
 				};
 
-				let dispatch_result = call.dispatch(origin.clone().into());
+				// Every proposal is dispatched on the same state to make the outcomes independent
+				// of each other.
+				let dispatch_result = with_transaction(|| {
+					TransactionOutcome::Rollback(Ok::<_, sp_runtime::DispatchError>(
+						call.dispatch(origin.clone().into()),
+					))
+				})
+				.expect("dispatching a proposal never fails the transaction; qed");
 				log::info!(target: LOG_TARGET, "outcome of dispatch with origin {origin:?}: {dispatch_result:?}");
+				outcomes.push(format!(
+					"#{ref_index}: {:?}",
+					dispatch_result.map(|_| ()).map_err(|e| e.error)
+				));
 			}
+
+			check_recorded_outcomes(state_snapshot(), "dispatch_all_proposals", outcomes);
 		});
 	}
 
 	#[tokio::test]
 	async fn run_migrations() {
-		if var("RUN_MIGRATION_TESTS").is_err() {
+		if !should_run("RUN_MIGRATION_TESTS") {
 			return;
 		}
 
 		sp_tracing::try_init_simple();
 		let mut ext = remote_ext_test_setup().await;
-		ext.execute_with(|| Runtime::on_runtime_upgrade(UpgradeCheckSelect::PreAndPost));
+		ext.execute_with(|| {
+			let (weight, max_weight) = Runtime::on_runtime_upgrade(UpgradeCheckSelect::PreAndPost);
+			assert!(
+				weight.all_lte(max_weight),
+				"migrations consume {weight:?}, more than a block can take: {max_weight:?}"
+			);
+		});
 	}
 
 	#[tokio::test]
@@ -3310,11 +4100,9 @@ mod remote_tests {
 	async fn next_inflation() {
 		use hex_literal::hex;
 		sp_tracing::try_init_simple();
-		let transport: Transport =
-			var("WS").unwrap_or("wss://rpc.dotters.network/polkadot".to_string()).into();
-		let mut ext = Builder::<Block>::default()
-			.mode(Mode::Online(OnlineConfig {
-				transport,
+		let mut ext = remote_ext_with(
+			"wss://rpc.dotters.network/polkadot",
+			OnlineConfig {
 				hashed_prefixes: vec![
 					// staking eras total stake
 					hex!("5f3e4907f716ac89b6347d15ececedcaa141c4fe67c2d11f4a10c6aca7a79a04")
@@ -3332,10 +4120,10 @@ mod remote_tests {
 						.to_vec(),
 				],
 				..Default::default()
-			}))
-			.build()
-			.await
-			.unwrap();
+			},
+			partial_state_snapshot("next_inflation"),
+		)
+		.await;
 		ext.execute_with(|| {
 			use pallet_staking::EraPayout;
 			let (total_staked, started) = pallet_staking::ActiveEra::<Runtime>::get()
@@ -3363,6 +4151,21 @@ mod remote_tests {
 			log::info!(target: LOG_TARGET, "maxStakingRewards = {:?}", pallet_staking::MaxStakedRewards::<Runtime>::get());
 			log::info!(target: LOG_TARGET, "💰 Inflation ==> staking = {:?} / leftover = {:?}", token.amount(staking), token.amount(leftover));
 			log::info!(target: LOG_TARGET, "inflation_rate runtime API: {:?}", Runtime::impl_experimental_inflation_info());
+
+			assert!(staking > 0, "no staking rewards in the next era");
+			assert_eq!(Runtime::impl_experimental_inflation_info().next_mint, (staking, leftover));
+			assert_eq!(
+				Runtime::impl_experimental_inflation_projection(1, &Default::default())
+					.first()
+					.map(|era| (era.staking_payout, era.rest)),
+				Some((staking, leftover))
+			);
+
+			check_recorded_outcomes(
+				partial_state_snapshot("next_inflation"),
+				"next_inflation",
+				vec![format!("staking = {staking}"), format!("leftover = {leftover}")],
+			);
 		});
 	}
 }
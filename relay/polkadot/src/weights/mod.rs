@@ -31,8 +31,10 @@ pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_nomination_pools;
+pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_recovery;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
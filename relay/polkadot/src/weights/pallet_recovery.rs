@@ -0,0 +1,182 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_recovery` on Polkadot.
+//!
+//! These are the weights generated for `pallet_recovery` on Kusama, which uses the same
+//! `MaxFriends` and thus the same storage bounds. This file is replaced by the output of
+//! `frame-omni-bencher` for this runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_recovery`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
+	/// Storage: `Recovery::Proxy` (r:1 w:0)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn as_recovered() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3545`
+		// Minimum execution time: 12_480_000 picoseconds.
+		Weight::from_parts(13_189_000, 0)
+			.saturating_add(Weight::from_parts(0, 3545))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Recovery::Proxy` (r:0 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn set_recovered() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_600_000 picoseconds.
+		Weight::from_parts(10_130_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3816`
+		// Minimum execution time: 31_920_000 picoseconds.
+		Weight::from_parts(33_771_736, 0)
+			.saturating_add(Weight::from_parts(0, 3816))
+			// Standard Error: 13_681
+			.saturating_add(Weight::from_parts(185_050, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `206`
+		//  Estimated: `3854`
+		// Minimum execution time: 38_460_000 picoseconds.
+		Weight::from_parts(40_980_000, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn vouch_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `294 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 22_930_000 picoseconds.
+		Weight::from_parts(24_304_412, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 8_572
+			.saturating_add(Weight::from_parts(235_418, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:0)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `326 + n * (64 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 28_060_000 picoseconds.
+		Weight::from_parts(30_695_960, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 31_581
+			.saturating_add(Weight::from_parts(82_668, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn close_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `447 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 44_109_000 picoseconds.
+		Weight::from_parts(46_305_565, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 11_388
+			.saturating_add(Weight::from_parts(222_757, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204 + n * (32 ±0)`
+		//  Estimated: `3854`
+		// Minimum execution time: 35_589_000 picoseconds.
+		Weight::from_parts(39_205_676, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			// Standard Error: 24_728
+			.saturating_add(Weight::from_parts(49_285, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn cancel_recovered() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3545`
+		// Minimum execution time: 14_840_000 picoseconds.
+		Weight::from_parts(15_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 3545))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn poke_deposit(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `940`
+		//  Estimated: `3854`
+		// Minimum execution time: 58_740_000 picoseconds.
+		Weight::from_parts(62_128_063, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
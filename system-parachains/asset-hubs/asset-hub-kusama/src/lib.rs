@@ -60,10 +60,13 @@ use frame_system::{
 	EnsureRoot, EnsureSigned, EnsureSignedBy,
 };
 use kusama_runtime_constants::time::MINUTES as RC_MINUTES;
-use pallet_assets::precompiles::{InlineIdConfig, ERC20};
+use pallet_assets::precompiles::{AssetIdExtractor, AssetPrecompileConfig, InlineIdConfig, ERC20};
 use pallet_nfts::PalletFeatures;
 use pallet_proxy::ProxyDefinition;
-use pallet_revive::evm::runtime::EthExtra;
+use pallet_revive::{
+	evm::runtime::EthExtra,
+	precompiles::{AddressMatcher, Error as PrecompileError},
+};
 use pallet_xcm::{precompiles::XcmPrecompile, EnsureXcm, IsVoiceOfBody};
 use parachains_common::{
 	message_queue::*, AccountId, AssetIdForTrustBackedAssets, AuraId, Balance, BlockNumber, Hash,
@@ -174,9 +177,31 @@ impl Contains<RuntimeCall> for VestedTransferCalls {
 	}
 }
 
+parameter_types! {
+	/// The maximum proof size a single contract execution can use.
+	///
+	/// A quarter of the PoV, so that one contract call can't fill a block on its own.
+	pub const MaxContractProofSize: u64 = MAXIMUM_BLOCK_WEIGHT.proof_size() / 4;
+}
+
+/// Contract calls and instantiations whose weight limit exceeds [`MaxContractProofSize`].
+pub struct OversizedContractCalls;
+impl Contains<RuntimeCall> for OversizedContractCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Revive(
+				pallet_revive::Call::call { weight_limit, .. } |
+				pallet_revive::Call::instantiate { weight_limit, .. } |
+				pallet_revive::Call::instantiate_with_code { weight_limit, .. },
+			) => weight_limit.proof_size() > MaxContractProofSize::get(),
+			_ => false,
+		}
+	}
+}
+
 // Configure FRAME pallets to include in runtime.
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = EverythingBut<VestedTransferCalls>;
+	type BaseCallFilter = EverythingBut<(VestedTransferCalls, OversizedContractCalls)>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
@@ -415,19 +440,14 @@ impl pallet_asset_conversion::Config for Runtime {
 	type AssetKind = Location;
 	type Assets = NativeAndAssets;
 	type PoolId = (Self::AssetKind, Self::AssetKind);
-	type PoolLocator = pallet_asset_conversion::WithFirstAsset<
-		KsmLocation,
-		AccountId,
-		Self::AssetKind,
-		PoolIdToAccountId,
-	>;
+	type PoolLocator = pallet_pool_fee_tiers::FeeTierPoolLocator<Runtime>;
 	type PoolAssetId = u32;
 	type PoolAssets = PoolAssets;
 	type PoolSetupFee = PoolSetupFee;
 	type PoolSetupFeeAsset = KsmLocation;
 	type PoolSetupFeeTarget = ResolveAssetTo<xcm_config::RelayTreasuryPalletAccount, Self::Assets>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
-	type LPFee = ConstU32<3>;
+	type LPFee = pallet_pool_fee_tiers::PoolFee<Runtime>;
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
@@ -441,6 +461,22 @@ impl pallet_asset_conversion::Config for Runtime {
 	>;
 }
 
+/// Lets pools charge one of the liquidity provider fees approved by governance.
+impl pallet_pool_fee_tiers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type InnerPoolLocator = pallet_asset_conversion::WithFirstAsset<
+		KsmLocation,
+		AccountId,
+		Location,
+		PoolIdToAccountId,
+	>;
+	// 0.3%, the fee of all pools before fee tiers were introduced.
+	type DefaultFee = ConstU32<3>;
+	type MaxFeeTiers = ConstU32<8>;
+	type FeeTiersOrigin = AssetsForceOrigin;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	// we just reuse the same deposits
 	pub const ForeignAssetsAssetDeposit: Balance = AssetDeposit::get();
@@ -481,14 +517,23 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type Freezer = ();
 	type Holder = ();
 	type Extra = ();
-	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
-	type CallbackHandle = ();
+	type WeightInfo = pallet_asset_index::CallbackWeightInfo<
+		Runtime,
+		weights::pallet_assets_foreign::WeightInfo<Runtime>,
+	>;
+	type CallbackHandle = ForeignAssetsIndex;
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
 
+/// Assigns a compact index to every foreign asset, which is used to derive the address of its
+/// ERC20 precompile.
+impl pallet_asset_index::Config for Runtime {
+	type AssetId = Location;
+}
+
 parameter_types! {
 	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
 	pub const DepositBase: Balance = system_para_deposit(1, 88);
@@ -585,7 +630,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::CancelProxy => matches!(
 				c,
 				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
-					RuntimeCall::Utility { .. } |
+					RuntimeCall::RemoteProxyRelayChain(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
 			ProxyType::Assets => {
@@ -1025,22 +1072,51 @@ impl
 			kusama_runtime_constants::proxy::ProxyType::Spokesperson |
 			kusama_runtime_constants::proxy::ProxyType::NominationPools |
 			kusama_runtime_constants::proxy::ProxyType::Society |
-			kusama_runtime_constants::proxy::ProxyType::ParaRegistration => return None,
+			kusama_runtime_constants::proxy::ProxyType::ParaRegistration |
+			kusama_runtime_constants::proxy::ProxyType::OnDemandOrdering => return None,
 		};
 
 		Some(ProxyDefinition {
 			delegate: a.delegate,
 			proxy_type,
-			// Delays are currently not supported by the remote proxy pallet, but should be
-			// converted in the future to the block time used by the local proxy pallet.
+			// The delay is already converted by the remote proxy interface. As the local proxy
+			// pallet uses the relay chain block number, it is the same as on the relay chain.
 			delay: a.delay,
 		})
 	}
 }
 
+/// The maximum age of a remote proxy proof per local proxy type.
+///
+/// Proofs of the most powerful proxy types are only accepted against the storage roots of the last
+/// few relay chain blocks, which leaves enough time to get a transaction with a fresh proof
+/// included. So, a proxy removed on the relay chain can not use older proofs for long. All other
+/// proxy types may use any of the kept storage roots.
+pub struct RemoteProxyMaxProofAge;
+
+impl Convert<ProxyType, u32> for RemoteProxyMaxProofAge {
+	fn convert(proxy_type: ProxyType) -> u32 {
+		match proxy_type {
+			ProxyType::Any | ProxyType::NonTransfer => 3,
+			ProxyType::CancelProxy |
+			ProxyType::Assets |
+			ProxyType::AssetOwner |
+			ProxyType::AssetManager |
+			ProxyType::Collator => RC_MINUTES,
+		}
+	}
+}
+
 impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	// The time between creating a proof and using the proof in a transaction.
 	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// The relay chain can push revocations of its proxies.
+	type RevocationOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<KsmLocation>>>;
+	type MaxRevocations = ConstU32<64>;
 	type RemoteProxy = kusama_runtime_constants::proxy::RemoteProxyInterface<
 		ProxyType,
 		RelayChainToLocalProxyTypeConverter,
@@ -1054,6 +1130,31 @@ parameter_types! {
 	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
 }
 
+/// Resolves the foreign asset of an ERC20 precompile address.
+///
+/// The first four bytes of the address are the big endian index assigned to the foreign asset by
+/// [`ForeignAssetsIndex`].
+pub struct ForeignAssetIdExtractor;
+impl AssetIdExtractor for ForeignAssetIdExtractor {
+	type AssetId = Location;
+
+	fn asset_id_from_address(address: &[u8; 20]) -> Result<Location, PrecompileError> {
+		let index = u32::from_be_bytes([address[0], address[1], address[2], address[3]]);
+		ForeignAssetsIndex::asset_id_of(index)
+			.ok_or_else(|| PrecompileError::Revert("Unknown foreign asset index".into()))
+	}
+}
+
+/// ERC20 precompile config for foreign assets.
+///
+/// Uses the same address layout as [`InlineIdConfig`], but the inlined id is the index of the
+/// foreign asset.
+pub struct ForeignIdConfig<const PREFIX: u16>;
+impl<const PREFIX: u16> AssetPrecompileConfig for ForeignIdConfig<PREFIX> {
+	const MATCHER: AddressMatcher = InlineIdConfig::<PREFIX>::MATCHER;
+	type AssetIdExtractor = ForeignAssetIdExtractor;
+}
+
 impl pallet_revive::Config for Runtime {
 	type Time = Timestamp;
 	type Currency = Balances;
@@ -1062,12 +1163,10 @@ impl pallet_revive::Config for Runtime {
 	type DepositPerItem = DepositPerItem;
 	type DepositPerByte = DepositPerByte;
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
-	// TODO(#840): use `weights::pallet_revive::WeightInfo` here
-	type WeightInfo = pallet_revive::weights::SubstrateWeight<Self>;
+	type WeightInfo = weights::pallet_revive::WeightInfo<Self>;
 	type Precompiles = (
 		ERC20<Self, InlineIdConfig<0x120>, TrustBackedAssetsInstance>,
-		// We will add ForeignAssetsInstance at <0x220> once we have Location to Id mapping
-		// ERC20<Self, InlineIdConfig<0x220>, ForeignAssetsInstance>,
+		ERC20<Self, ForeignIdConfig<0x220>, ForeignAssetsInstance>,
 		ERC20<Self, InlineIdConfig<0x320>, PoolAssetsInstance>,
 		XcmPrecompile<Self>,
 	);
@@ -1133,6 +1232,8 @@ construct_runtime!(
 
 		PoolAssets: pallet_assets::<Instance3> = 55,
 		AssetConversion: pallet_asset_conversion = 56,
+		ForeignAssetsIndex: pallet_asset_index = 57,
+		PoolFeeTiers: pallet_pool_fee_tiers = 58,
 
 		Revive: pallet_revive = 60,
 
@@ -1207,7 +1308,11 @@ pub mod migrations {
 	pub type Permanent = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
 
 	/// MBM migrations to apply on runtime upgrade.
-	pub type MbmMigrations = pallet_revive::migrations::v1::Migration<Runtime>;
+	pub type MbmMigrations = (
+		pallet_revive::migrations::v1::Migration<Runtime>,
+		pallet_asset_index::migration::IndexExistingAssets<Runtime, ForeignAssetsInstance>,
+		pallet_pool_fee_tiers::migration::AssignDefaultFee<Runtime>,
+	);
 }
 
 /// Executive: handles dispatch to the various modules.
@@ -1313,8 +1418,7 @@ mod benches {
 		[pallet_nfts, Nfts]
 		[pallet_proxy, Proxy]
 		[pallet_remote_proxy, RemoteProxyRelayChain]
-		// TODO(#840): uncomment this so that pallet-revive is also benchmarked with this runtime
-		// [pallet_revive, Revive]
+		[pallet_revive, Revive]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
@@ -1722,6 +1826,8 @@ mod benches {
 #[cfg(feature = "runtime-benchmarks")]
 use benches::*;
 
+// Besides the runtime APIs below, this also implements `pallet_revive::ReviveApi`, which is used by
+// the Ethereum JSON-RPC adapter to dry-run calls, estimate gas and trace transactions.
 pallet_revive::impl_runtime_apis_plus_revive!(
 	Runtime,
 	Executive,
@@ -2011,6 +2117,50 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<
+		Block,
+		AccountId,
+		ProxyType,
+		BlockNumber,
+		BlockNumber,
+	> for Runtime
+	{
+		fn remote_storage_keys(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+		) -> Option<pallet_remote_proxy::RemoteProxyStorageKeys> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::remote_storage_keys(&real)
+			} else {
+				None
+			}
+		}
+
+		fn acceptable_anchor_blocks(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			proxy_type: Option<ProxyType>,
+		) -> Vec<BlockNumber> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::acceptable_anchor_blocks(proxy_type)
+			} else {
+				Vec::new()
+			}
+		}
+
+		fn validate_proof(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+			delegate: AccountId,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<Vec<pallet_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>>, sp_runtime::DispatchError> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else {
+				Err(pallet_remote_proxy::Error::<Runtime>::UnsupportedProofKind.into())
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -2140,6 +2290,71 @@ mod tests {
 		assert!(fit >= 1000, "{fit} should be at least 1000");
 	}
 
+	/// A call to a contract of maximum size fits into a block, also with respect to the proof size
+	/// of loading its code. At least 10 of them fit into a block and loading the code stays within
+	/// [`MaxContractProofSize`].
+	#[test]
+	fn max_size_contract_call_fits_into_block() {
+		use pallet_revive::WeightInfo;
+		type ReviveWeights = weights::pallet_revive::WeightInfo<Runtime>;
+
+		let max_code_len = pallet_revive::limits::code::BLOB_BYTES;
+		let block = RuntimeBlockWeights::get().max_block;
+		let normal = RuntimeBlockWeights::get().get(DispatchClass::Normal);
+		let max_extrinsic = normal.max_extrinsic.expect("Normal extrinsics are limited");
+
+		for (name, weight) in [
+			("call", ReviveWeights::call_with_code_per_byte(max_code_len)),
+			("instantiate_with_code", ReviveWeights::instantiate_with_code(max_code_len, 0)),
+			("upload_code", ReviveWeights::upload_code(max_code_len)),
+		] {
+			let weight = normal.base_extrinsic + weight;
+			assert!(weight.all_lte(max_extrinsic), "{name}: {weight:?} exceeds {max_extrinsic:?}");
+			assert!(
+				weight.proof_size() <= MaxContractProofSize::get(),
+				"{name}: proof size {} exceeds the contract proof size limit",
+				weight.proof_size()
+			);
+
+			let fit = block.checked_div_per_component(&weight).unwrap_or_default();
+			assert!(fit >= 10, "{name}: {fit} should be at least 10");
+		}
+	}
+
+	/// Contract calls with a weight limit above [`MaxContractProofSize`] are filtered.
+	#[test]
+	fn oversized_contract_calls_are_filtered() {
+		use frame_support::traits::Contains;
+
+		let call = |proof_size| {
+			RuntimeCall::Revive(pallet_revive::Call::call {
+				dest: Default::default(),
+				value: 0,
+				weight_limit: Weight::from_parts(1_000_000_000, proof_size),
+				storage_deposit_limit: 0,
+				data: Default::default(),
+			})
+		};
+		let instantiate = |proof_size| {
+			RuntimeCall::Revive(pallet_revive::Call::instantiate {
+				value: 0,
+				weight_limit: Weight::from_parts(1_000_000_000, proof_size),
+				storage_deposit_limit: 0,
+				code_hash: Default::default(),
+				data: Default::default(),
+				salt: None,
+			})
+		};
+		let limit = MaxContractProofSize::get();
+
+		for call in [call(limit), instantiate(limit)] {
+			assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(&call));
+		}
+		for call in [call(limit + 1), instantiate(limit + 1)] {
+			assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&call));
+		}
+	}
+
 	/// The fee for one transfer is at most 1 CENT.
 	#[test]
 	fn sane_transfer_fee() {
@@ -2202,4 +2417,25 @@ mod tests {
 			AccountId::from_ss58check("5F4EbSkZz18X36xhbsjvDNs6NuZ82HyYtq5UiJ1h9SBHJXZD").unwrap();
 		assert_eq!(acc, MigController::sorted_members()[0]);
 	}
+
+	/// Proofs of the most powerful proxy types are only accepted against the latest few storage roots.
+	#[test]
+	fn remote_proxy_max_proof_age_depends_on_proxy_type() {
+		use frame_support::traits::Get;
+
+		type MaxProofAge = <Runtime as pallet_remote_proxy::Config>::MaxProofAge;
+		let max_roots: u32 = <Runtime as pallet_remote_proxy::Config>::MaxStorageRootsToKeep::get();
+
+		assert_eq!(MaxProofAge::convert(ProxyType::Any), 3);
+		assert_eq!(MaxProofAge::convert(ProxyType::NonTransfer), 3);
+		for proxy_type in [
+			ProxyType::CancelProxy,
+			ProxyType::Assets,
+			ProxyType::AssetOwner,
+			ProxyType::AssetManager,
+			ProxyType::Collator,
+		] {
+			assert_eq!(MaxProofAge::convert(proxy_type), max_roots);
+		}
+	}
 }
@@ -28,9 +28,10 @@ use asset_hub_kusama_runtime::{
 		TrustBackedAssetsPalletLocation, XcmConfig,
 	},
 	AllPalletsWithoutSystem, AssetDeposit, Assets, Balances, Block, ExistentialDeposit,
-	ForeignAssets, ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte,
-	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
-	ToPolkadotXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue, SLOT_DURATION,
+	ForeignAssetIdExtractor, ForeignAssets, ForeignAssetsIndex, ForeignAssetsInstance,
+	MetadataDepositBase, MetadataDepositPerByte, ParachainSystem, PolkadotXcm, Revive, Runtime,
+	RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys, ToPolkadotXcmRouterInstance,
+	TrustBackedAssetsInstance, XcmpQueue, SLOT_DURATION,
 };
 use asset_test_utils::{
 	include_create_and_manage_foreign_assets_for_local_consensus_parachain_assets_works,
@@ -900,3 +901,115 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+#[test]
+fn foreign_assets_erc20_precompile_resolves_indexed_assets() {
+	use pallet_assets::precompiles::AssetIdExtractor;
+
+	ExtBuilder::<Runtime>::default()
+		.with_tracing()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let bridged_eth = Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]);
+			let sibling_token = Location::new(1, [Parachain(1234), GeneralIndex(12345)]);
+			let precompile_address = |index: u32| {
+				let mut address = [0u8; 20];
+				address[..4].copy_from_slice(&index.to_be_bytes());
+				address[16..18].copy_from_slice(&0x0220u16.to_be_bytes());
+				address
+			};
+
+			assert_eq!(ForeignAssetsIndex::index_of(&bridged_eth), None);
+
+			for asset_id in [&bridged_eth, &sibling_token] {
+				assert_ok!(ForeignAssets::force_create(
+					RuntimeHelper::root_origin(),
+					asset_id.clone(),
+					AccountId::from(SOME_ASSET_ADMIN).into(),
+					false,
+					1
+				));
+			}
+
+			// Created foreign assets get consecutive indices.
+			let eth_index = ForeignAssetsIndex::index_of(&bridged_eth).unwrap();
+			assert_eq!(ForeignAssetsIndex::index_of(&sibling_token), Some(eth_index + 1));
+
+			// The precompile address resolves to the foreign asset with the inlined index.
+			assert_eq!(
+				ForeignAssetIdExtractor::asset_id_from_address(&precompile_address(eth_index)).ok(),
+				Some(bridged_eth)
+			);
+			assert_eq!(
+				ForeignAssetIdExtractor::asset_id_from_address(&precompile_address(eth_index + 1))
+					.ok(),
+				Some(sibling_token)
+			);
+			assert!(ForeignAssetIdExtractor::asset_id_from_address(&precompile_address(
+				eth_index + 2
+			))
+			.is_err());
+		})
+}
+
+#[test]
+fn revive_api_deploys_and_calls_contracts() {
+	use frame_support::{traits::fungible::Mutate, weights::Weight};
+	use pallet_revive::{runtime_decl_for_revive_api::ReviveApiV1, AddressMapper, Code};
+	use sp_core::U256;
+
+	ExtBuilder::<Runtime>::default()
+		.with_tracing()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let alice = AccountId::from(ALICE);
+			let alice_address =
+				<Runtime as pallet_revive::Config>::AddressMapper::to_address(&alice);
+			assert_ok!(Balances::mint_into(&alice, 100 * UNITS));
+			assert_ok!(Revive::map_account(RuntimeOrigin::signed(alice.clone())));
+
+			// Balances are reported in Ethereum units (18 decimals instead of 12).
+			let eth_balance = Runtime::balance(alice_address);
+			assert!(eth_balance >= U256::from(99 * UNITS) * U256::from(1_000_000));
+			assert!(Runtime::gas_price() > U256::zero());
+			assert!(Runtime::block_gas_limit() > U256::zero());
+
+			// Deploy a contract.
+			let (code, _) = pallet_revive_fixtures::compile_module("dummy").unwrap();
+			let instantiate_result = Runtime::instantiate(
+				alice.clone(),
+				0,
+				None,
+				None,
+				Code::Upload(code),
+				vec![],
+				None,
+			);
+			assert!(instantiate_result.gas_required.all_gt(Weight::zero()));
+			let instantiated = instantiate_result.result.expect("contract is instantiated");
+			assert!(!instantiated.result.did_revert());
+			let contract = instantiated.addr;
+
+			// The contract account exists and its storage can be queried.
+			assert_eq!(Runtime::get_storage(contract, [0u8; 32]), Ok(None));
+
+			// Call the contract.
+			let returned = Runtime::call(alice.clone(), contract, 0, None, None, vec![])
+				.result
+				.expect("contract is called");
+			assert!(!returned.did_revert());
+			assert!(returned.data.is_empty());
+		})
+}
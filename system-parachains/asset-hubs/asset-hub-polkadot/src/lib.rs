@@ -77,11 +77,10 @@ use assets_common::{
 };
 use cumulus_pallet_parachain_system::{RelayNumberMonotonicallyIncreases, RelaychainDataProvider};
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
-use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, ConstU128, OpaqueMetadata};
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, Verify},
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertInto, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill, Permill,
 };
@@ -113,7 +112,13 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot, EnsureSigned, EnsureSignedBy,
 };
+use pallet_assets::precompiles::{AssetIdExtractor, AssetPrecompileConfig, InlineIdConfig, ERC20};
 use pallet_nfts::PalletFeatures;
+use pallet_proxy::ProxyDefinition;
+use pallet_revive::{
+	evm::runtime::EthExtra,
+	precompiles::{AddressMatcher, Error as PrecompileError},
+};
 use parachains_common::{
 	message_queue::*, AccountId, AssetHubPolkadotAuraId as AuraId, AssetIdForTrustBackedAssets,
 	Balance, BlockNumber, Hash, Header, Nonce, Signature,
@@ -151,7 +156,7 @@ use xcm_config::{
 pub use sp_runtime::BuildStorage;
 
 // Polkadot imports
-use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
+use pallet_xcm::{precompiles::XcmPrecompile, EnsureXcm, IsVoiceOfBody};
 use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 
@@ -242,12 +247,31 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = ConstU32<64>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = migrations::MbmMigrations;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
 	type Moment = u64;
@@ -412,14 +436,23 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type Freezer = ();
 	type Holder = ();
 	type Extra = ();
-	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
-	type CallbackHandle = ();
+	type WeightInfo = pallet_asset_index::CallbackWeightInfo<
+		Runtime,
+		weights::pallet_assets_foreign::WeightInfo<Runtime>,
+	>;
+	type CallbackHandle = ForeignAssetsIndex;
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
 }
 
+/// Assigns a compact index to every foreign asset, which is used to derive the address of its
+/// ERC20 precompile.
+impl pallet_asset_index::Config for Runtime {
+	type AssetId = Location;
+}
+
 parameter_types! {
 	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
 	pub const DepositBase: Balance = system_para_deposit(1, 88);
@@ -513,12 +546,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Session(_) |
 					RuntimeCall::Utility(_) |
 					RuntimeCall::Multisig(_) |
-					RuntimeCall::Proxy(_)
+					RuntimeCall::Proxy(_) |
+					RuntimeCall::RemoteProxyRelayChain(_)
 			),
 			ProxyType::CancelProxy => matches!(
 				c,
 				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
-					RuntimeCall::Utility { .. } |
+					RuntimeCall::RemoteProxyRelayChain(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
 			ProxyType::Assets => {
@@ -633,6 +669,140 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 }
 
+/// Converts from the relay chain proxy type to the local proxy type.
+pub struct RelayChainToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for RelayChainToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			polkadot_runtime_constants::proxy::ProxyType::Any => ProxyType::Any,
+			polkadot_runtime_constants::proxy::ProxyType::NonTransfer => ProxyType::NonTransfer,
+			polkadot_runtime_constants::proxy::ProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on AH.
+			polkadot_runtime_constants::proxy::ProxyType::Governance |
+			polkadot_runtime_constants::proxy::ProxyType::Staking |
+			polkadot_runtime_constants::proxy::ProxyType::Auction |
+			polkadot_runtime_constants::proxy::ProxyType::NominationPools |
+			polkadot_runtime_constants::proxy::ProxyType::ParaRegistration |
+			polkadot_runtime_constants::proxy::ProxyType::Spokesperson |
+			polkadot_runtime_constants::proxy::ProxyType::ParaManagement |
+			polkadot_runtime_constants::proxy::ProxyType::OnDemandOrdering => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is already converted to local blocks by the remote proxy interface.
+			delay: a.delay,
+		})
+	}
+}
+
+/// The maximum age of a remote proxy proof per local proxy type.
+///
+/// Proofs of the most powerful proxy types are only accepted against the storage roots of the last
+/// few relay chain blocks, which leaves enough time to get a transaction with a fresh proof
+/// included. So, a proxy removed on the relay chain can not use older proofs for long. All other
+/// proxy types may use any of the kept storage roots.
+pub struct RemoteProxyMaxProofAge;
+
+impl Convert<ProxyType, u32> for RemoteProxyMaxProofAge {
+	fn convert(proxy_type: ProxyType) -> u32 {
+		match proxy_type {
+			ProxyType::Any | ProxyType::NonTransfer => 3,
+			ProxyType::CancelProxy |
+			ProxyType::Assets |
+			ProxyType::AssetOwner |
+			ProxyType::AssetManager |
+			ProxyType::Collator => polkadot_runtime_constants::time::MINUTES,
+		}
+	}
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// The relay chain can push revocations of its proxies.
+	type RevocationOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<DotLocation>>>;
+	type MaxRevocations = ConstU32<64>;
+	type RemoteProxy = polkadot_runtime_constants::proxy::RemoteProxyInterface<
+		ProxyType,
+		RelayChainToLocalProxyTypeConverter,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const DepositPerItem: Balance = system_para_deposit(1, 0);
+	pub const DepositPerByte: Balance = system_para_deposit(0, 1);
+	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+}
+
+/// Resolves the foreign asset of an ERC20 precompile address.
+///
+/// The first four bytes of the address are the big endian index assigned to the foreign asset by
+/// [`ForeignAssetsIndex`].
+pub struct ForeignAssetIdExtractor;
+impl AssetIdExtractor for ForeignAssetIdExtractor {
+	type AssetId = Location;
+
+	fn asset_id_from_address(address: &[u8; 20]) -> Result<Location, PrecompileError> {
+		let index = u32::from_be_bytes([address[0], address[1], address[2], address[3]]);
+		ForeignAssetsIndex::asset_id_of(index)
+			.ok_or_else(|| PrecompileError::Revert("Unknown foreign asset index".into()))
+	}
+}
+
+/// ERC20 precompile config for foreign assets.
+///
+/// Uses the same address layout as [`InlineIdConfig`], but the inlined id is the index of the
+/// foreign asset.
+pub struct ForeignIdConfig<const PREFIX: u16>;
+impl<const PREFIX: u16> AssetPrecompileConfig for ForeignIdConfig<PREFIX> {
+	const MATCHER: AddressMatcher = InlineIdConfig::<PREFIX>::MATCHER;
+	type AssetIdExtractor = ForeignAssetIdExtractor;
+}
+
+impl pallet_revive::Config for Runtime {
+	type Time = Timestamp;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type DepositPerItem = DepositPerItem;
+	type DepositPerByte = DepositPerByte;
+	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+	type WeightInfo = weights::pallet_revive::WeightInfo<Self>;
+	type Precompiles = (
+		ERC20<Self, InlineIdConfig<0x120>, TrustBackedAssetsInstance>,
+		ERC20<Self, ForeignIdConfig<0x220>, ForeignAssetsInstance>,
+		ERC20<Self, InlineIdConfig<0x320>, PoolAssetsInstance>,
+		XcmPrecompile<Self>,
+	);
+	type AddressMapper = pallet_revive::AccountId32Mapper<Self>;
+	type RuntimeMemory = ConstU32<{ 128 * 1024 * 1024 }>;
+	type PVFMemory = ConstU32<{ 512 * 1024 * 1024 }>;
+	type UnsafeUnstableInterface = ConstBool<false>;
+	type UploadOrigin = EnsureSigned<Self::AccountId>;
+	type InstantiateOrigin = EnsureSigned<Self::AccountId>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type ChainId = ConstU64<420_420_419>;
+	type NativeToEthRatio = ConstU32<100_000_000>; // 10^(18 - 10) Eth is 10^18, Native is 10^10.
+	type EthGasEncoder = ();
+	type FindAuthor = <Runtime as pallet_authorship::Config>::FindAuthor;
+}
+
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -641,7 +811,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RemoteProxyRelayChain;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -973,19 +1143,14 @@ impl pallet_asset_conversion::Config for Runtime {
 	type AssetKind = Location;
 	type Assets = NativeAndAssets;
 	type PoolId = (Self::AssetKind, Self::AssetKind);
-	type PoolLocator = pallet_asset_conversion::WithFirstAsset<
-		DotLocation,
-		AccountId,
-		Self::AssetKind,
-		PoolIdToAccountId,
-	>;
+	type PoolLocator = pallet_pool_fee_tiers::FeeTierPoolLocator<Runtime>;
 	type PoolAssetId = u32;
 	type PoolAssets = PoolAssets;
 	type PoolSetupFee = PoolSetupFee;
 	type PoolSetupFeeAsset = DotLocation;
 	type PoolSetupFeeTarget = ResolveAssetTo<xcm_config::RelayTreasuryPalletAccount, Self::Assets>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
-	type LPFee = ConstU32<3>;
+	type LPFee = pallet_pool_fee_tiers::PoolFee<Runtime>;
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
@@ -999,6 +1164,22 @@ impl pallet_asset_conversion::Config for Runtime {
 	>;
 }
 
+/// Lets pools charge one of the liquidity provider fees approved by governance.
+impl pallet_pool_fee_tiers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type InnerPoolLocator = pallet_asset_conversion::WithFirstAsset<
+		DotLocation,
+		AccountId,
+		Location,
+		PoolIdToAccountId,
+	>;
+	// 0.3%, the fee of all pools before fee tiers were introduced.
+	type DefaultFee = ConstU32<3>;
+	type MaxFeeTiers = ConstU32<8>;
+	type FeeTiersOrigin = AssetsForceOrigin;
+	type WeightInfo = ();
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -1009,6 +1190,7 @@ construct_runtime!(
 		// RandomnessCollectiveFlip = 2 removed
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
+		MultiBlockMigrations: pallet_migrations = 5,
 
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
@@ -1036,6 +1218,7 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteProxyRelayChain: pallet_remote_proxy = 43,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1> = 50,
@@ -1044,6 +1227,10 @@ construct_runtime!(
 		ForeignAssets: pallet_assets::<Instance2> = 53,
 		PoolAssets: pallet_assets::<Instance3> = 54,
 		AssetConversion: pallet_asset_conversion = 55,
+		ForeignAssetsIndex: pallet_asset_index = 57,
+		PoolFeeTiers: pallet_pool_fee_tiers = 58,
+
+		Revive: pallet_revive = 60,
 
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 70,
@@ -1070,9 +1257,33 @@ pub type TxExtension = (
 	pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
+
+/// Default extensions applied to Ethereum transactions.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EthExtraImpl;
+
+impl EthExtra for EthExtraImpl {
+	type Config = Runtime;
+	type Extension = TxExtension;
+
+	fn get_eth_extension(nonce: u32, tip: Balance) -> Self::Extension {
+		(
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckMortality::from(generic::Era::Immortal),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+		)
+	}
+}
+
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
-	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
+	pallet_revive::evm::runtime::UncheckedExtrinsic<Address, Signature, EthExtraImpl>;
 
 /// All migrations that will run on the next runtime upgrade.
 ///
@@ -1098,7 +1309,10 @@ pub mod migrations {
 	pub type Permanent = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
 
 	/// MBM migrations to apply on runtime upgrade.
-	pub type MbmMigrations = ();
+	pub type MbmMigrations = (
+		pallet_asset_index::migration::IndexExistingAssets<Runtime, ForeignAssetsInstance>,
+		pallet_pool_fee_tiers::migration::AssignDefaultFee<Runtime>,
+	);
 }
 
 /// Executive: handles dispatch to the various modules.
@@ -1197,9 +1411,12 @@ mod benches {
 		[pallet_asset_conversion_tx_payment, AssetTxPayment]
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nfts, Nfts]
 		[pallet_proxy, Proxy]
+		[pallet_remote_proxy, RemoteProxyRelayChain]
+		[pallet_revive, Revive]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
@@ -1612,7 +1829,12 @@ mod benches {
 #[cfg(feature = "runtime-benchmarks")]
 use benches::*;
 
-impl_runtime_apis! {
+// Besides the runtime APIs below, this also implements `pallet_revive::ReviveApi`, which is used by
+// the Ethereum JSON-RPC adapter to dry-run calls, estimate gas and trace transactions.
+pallet_revive::impl_runtime_apis_plus_revive!(
+	Runtime,
+	Executive,
+	EthExtraImpl,
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
 			sp_consensus_aura::SlotDuration::from_millis(SLOT_DURATION)
@@ -1879,6 +2101,50 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<
+		Block,
+		AccountId,
+		ProxyType,
+		BlockNumber,
+		BlockNumber,
+	> for Runtime
+	{
+		fn remote_storage_keys(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+		) -> Option<pallet_remote_proxy::RemoteProxyStorageKeys> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::remote_storage_keys(&real)
+			} else {
+				None
+			}
+		}
+
+		fn acceptable_anchor_blocks(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			proxy_type: Option<ProxyType>,
+		) -> Vec<BlockNumber> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::acceptable_anchor_blocks(proxy_type)
+			} else {
+				Vec::new()
+			}
+		}
+
+		fn validate_proof(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+			delegate: AccountId,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<Vec<pallet_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>>, sp_runtime::DispatchError> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else {
+				Err(pallet_remote_proxy::Error::<Runtime>::UnsupportedProofKind.into())
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -1985,7 +2251,7 @@ impl_runtime_apis! {
 			Ok(batches)
 		}
 	}
-}
+);
 
 cumulus_pallet_parachain_system::register_validate_block! {
 	Runtime = Runtime,
@@ -2107,4 +2373,44 @@ mod tests {
 			AccountId::from_ss58check("5F4EbSkZz18X36xhbsjvDNs6NuZ82HyYtq5UiJ1h9SBHJXZD").unwrap();
 		assert_eq!(acc, MigController::sorted_members()[0]);
 	}
+
+	/// Proofs of the most powerful proxy types are only accepted against the latest few storage roots.
+	#[test]
+	fn remote_proxy_max_proof_age_depends_on_proxy_type() {
+		use frame_support::traits::Get;
+
+		type MaxProofAge = <Runtime as pallet_remote_proxy::Config>::MaxProofAge;
+		let max_roots: u32 = <Runtime as pallet_remote_proxy::Config>::MaxStorageRootsToKeep::get();
+
+		assert_eq!(MaxProofAge::convert(ProxyType::Any), 3);
+		assert_eq!(MaxProofAge::convert(ProxyType::NonTransfer), 3);
+		for proxy_type in [
+			ProxyType::CancelProxy,
+			ProxyType::Assets,
+			ProxyType::AssetOwner,
+			ProxyType::AssetManager,
+			ProxyType::Collator,
+		] {
+			assert_eq!(MaxProofAge::convert(proxy_type), max_roots);
+		}
+	}
+
+	/// Other chains decode the proxy definitions of Asset Hub with `AssetHubProxyType`.
+	#[test]
+	fn asset_hub_proxy_type_matches_proxy_type() {
+		use system_parachains_constants::polkadot::proxy::AssetHubProxyType;
+
+		for (proxy_type, asset_hub_proxy_type) in [
+			(ProxyType::Any, AssetHubProxyType::Any),
+			(ProxyType::NonTransfer, AssetHubProxyType::NonTransfer),
+			(ProxyType::CancelProxy, AssetHubProxyType::CancelProxy),
+			(ProxyType::Assets, AssetHubProxyType::Assets),
+			(ProxyType::AssetOwner, AssetHubProxyType::AssetOwner),
+			(ProxyType::AssetManager, AssetHubProxyType::AssetManager),
+			(ProxyType::Collator, AssetHubProxyType::Collator),
+		] {
+			assert_eq!(proxy_type.encode(), asset_hub_proxy_type.encode());
+		}
+		assert_eq!(ProxyType::default().encode(), AssetHubProxyType::default().encode());
+	}
 }
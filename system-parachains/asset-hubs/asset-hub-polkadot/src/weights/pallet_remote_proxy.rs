@@ -0,0 +1,57 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_remote_proxy` (RemoteProxyRelayChain) on Asset Hub Polkadot.
+//!
+//! The execution times are the ones generated for `pallet_remote_proxy` on Asset Hub Kusama. The
+//! proof sizes and reads additionally account for `VerifiedProxyDefinitions` and `RevokedProxies`.
+//! The calls that are not listed here use the defaults of `pallet_remote_proxy::WeightInfo`. This
+//! file is replaced by the output of `frame-omni-bencher` for this runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_remote_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T> {
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyRelayChain::VerifiedProxyDefinitions` (r:1 w:0)
+	/// Storage: `RemoteProxyRelayChain::RevokedProxies` (r:1 w:0)
+	fn remote_proxy() -> Weight {
+		Weight::from_parts(21_309_000, 0)
+			.saturating_add(Weight::from_parts(0, 5846))
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+	fn register_remote_proxy_proof() -> Weight {
+		Weight::from_parts(4_931_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyRelayChain::VerifiedProxyDefinitions` (r:1 w:0)
+	/// Storage: `RemoteProxyRelayChain::RevokedProxies` (r:1 w:0)
+	fn remote_proxy_with_registered_proof() -> Weight {
+		Weight::from_parts(21_550_000, 0)
+			.saturating_add(Weight::from_parts(0, 5846))
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+}
@@ -0,0 +1,1080 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_revive` on Asset Hub Polkadot.
+//!
+//! The values are the ones generated for `pallet_revive` on Asset Hub Kusama, which runs the same
+//! pallet version. Reads and writes are priced with the database weights of this runtime. This
+//! file is replaced by the output of `frame-omni-bencher` for this runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_revive`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_revive::WeightInfo for WeightInfo<T> {
+	/// Storage: `Revive::DeletionQueueCounter` (r:1 w:0)
+	/// Proof: `Revive::DeletionQueueCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	fn on_process_deletion_queue_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `1594`
+		// Minimum execution time: 3_080_000 picoseconds.
+		Weight::from_parts(3_290_000, 0)
+			.saturating_add(Weight::from_parts(0, 1594))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[0, 1024]`.
+	fn on_initialize_per_trie_key(k: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430 + k * (69 ±0)`
+		//  Estimated: `420 + k * (70 ±0)`
+		// Minimum execution time: 15_490_000 picoseconds.
+		Weight::from_parts(15_750_000, 0)
+			.saturating_add(Weight::from_parts(0, 420))
+			// Standard Error: 969
+			.saturating_add(Weight::from_parts(1_376_154, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+			.saturating_add(Weight::from_parts(0, 70).saturating_mul(k.into()))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:2 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// The range of component `c` is `[0, 104857]`.
+	fn call_with_code_per_byte(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `747 + c * (1 ±0)`
+		//  Estimated: `6684 + c * (1 ±0)`
+		// Minimum execution time: 91_410_000 picoseconds.
+		Weight::from_parts(125_506_650, 0)
+			.saturating_add(Weight::from_parts(0, 6684))
+			// Standard Error: 8
+			.saturating_add(Weight::from_parts(1_339, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(c.into()))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:2 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// The range of component `b` is `[0, 1]`.
+	fn basic_block_compilation(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4082`
+		//  Estimated: `10022`
+		// Minimum execution time: 132_860_000 picoseconds.
+		Weight::from_parts(134_479_679, 0)
+			.saturating_add(Weight::from_parts(0, 10022))
+			// Standard Error: 94_430
+			.saturating_add(Weight::from_parts(435_620, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:0 w:1)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// The range of component `c` is `[0, 104857]`.
+	/// The range of component `i` is `[0, 262144]`.
+	fn instantiate_with_code(c: u32, i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336`
+		//  Estimated: `6245`
+		// Minimum execution time: 1_413_911_000 picoseconds.
+		Weight::from_parts(183_158_121, 0)
+			.saturating_add(Weight::from_parts(0, 6245))
+			// Standard Error: 18
+			.saturating_add(Weight::from_parts(21_952, 0).saturating_mul(c.into()))
+			// Standard Error: 7
+			.saturating_add(Weight::from_parts(4_653, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// The range of component `i` is `[0, 262144]`.
+	fn instantiate(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1227`
+		//  Estimated: `4677`
+		// Minimum execution time: 171_410_000 picoseconds.
+		Weight::from_parts(183_569_424, 0)
+			.saturating_add(Weight::from_parts(0, 4677))
+			// Standard Error: 6
+			.saturating_add(Weight::from_parts(4_680, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:2 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	fn call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1383`
+		//  Estimated: `7323`
+		// Minimum execution time: 91_761_000 picoseconds.
+		Weight::from_parts(93_270_000, 0)
+			.saturating_add(Weight::from_parts(0, 7323))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:0 w:1)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// The range of component `c` is `[0, 104857]`.
+	fn upload_code(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3574`
+		// Minimum execution time: 50_630_000 picoseconds.
+		Weight::from_parts(37_830_655, 0)
+			.saturating_add(Weight::from_parts(0, 3574))
+			// Standard Error: 19
+			.saturating_add(Weight::from_parts(16_468, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:0 w:1)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	fn remove_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `282`
+		//  Estimated: `3747`
+		// Minimum execution time: 42_200_000 picoseconds.
+		Weight::from_parts(42_590_000, 0)
+			.saturating_add(Weight::from_parts(0, 3747))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:2 w:2)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	fn set_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `524`
+		//  Estimated: `6464`
+		// Minimum execution time: 23_750_000 picoseconds.
+		Weight::from_parts(24_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 6464))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:1 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	fn map_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `3574`
+		// Minimum execution time: 48_060_000 picoseconds.
+		Weight::from_parts(48_641_000, 0)
+			.saturating_add(Weight::from_parts(0, 3574))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `Revive::OriginalAccount` (r:0 w:1)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	fn unmap_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55`
+		//  Estimated: `3520`
+		// Minimum execution time: 37_740_000 picoseconds.
+		Weight::from_parts(38_180_000, 0)
+			.saturating_add(Weight::from_parts(0, 3520))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn dispatch_as_fallback_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_180_000 picoseconds.
+		Weight::from_parts(6_460_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `r` is `[0, 1600]`.
+	fn noop_host_fn(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_620_000 picoseconds.
+		Weight::from_parts(9_583_401, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 247
+			.saturating_add(Weight::from_parts(193_582, 0).saturating_mul(r.into()))
+	}
+	fn seal_caller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_160_000 picoseconds.
+		Weight::from_parts(1_250_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_190_000 picoseconds.
+		Weight::from_parts(1_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	fn seal_is_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `306`
+		//  Estimated: `3771`
+		// Minimum execution time: 8_010_000 picoseconds.
+		Weight::from_parts(8_200_000, 0)
+			.saturating_add(Weight::from_parts(0, 3771))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	fn seal_to_account_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `269`
+		//  Estimated: `3734`
+		// Minimum execution time: 7_700_000 picoseconds.
+		Weight::from_parts(7_880_000, 0)
+			.saturating_add(Weight::from_parts(0, 3734))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	fn seal_code_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3867`
+		// Minimum execution time: 8_870_000 picoseconds.
+		Weight::from_parts(9_090_000, 0)
+			.saturating_add(Weight::from_parts(0, 3867))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn seal_own_code_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 370_000 picoseconds.
+		Weight::from_parts(410_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	fn seal_code_size() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `470`
+		//  Estimated: `3935`
+		// Minimum execution time: 12_550_000 picoseconds.
+		Weight::from_parts(12_990_000, 0)
+			.saturating_add(Weight::from_parts(0, 3935))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	fn seal_caller_is_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 390_000 picoseconds.
+		Weight::from_parts(450_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_caller_is_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 350_000 picoseconds.
+		Weight::from_parts(390_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 330_000 picoseconds.
+		Weight::from_parts(400_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_weight_left() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 780_000 picoseconds.
+		Weight::from_parts(880_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_ref_time_left() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 280_000 picoseconds.
+		Weight::from_parts(340_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `0`
+		// Minimum execution time: 6_280_000 picoseconds.
+		Weight::from_parts(6_420_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	fn seal_balance_of() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3750`
+		// Minimum execution time: 10_150_000 picoseconds.
+		Weight::from_parts(10_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 3750))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `Revive::ImmutableDataOf` (r:1 w:0)
+	/// Proof: `Revive::ImmutableDataOf` (`max_values`: None, `max_size`: Some(4118), added: 6593, mode: `Measured`)
+	/// The range of component `n` is `[1, 4096]`.
+	fn seal_get_immutable_data(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `238 + n * (1 ±0)`
+		//  Estimated: `3703 + n * (1 ±0)`
+		// Minimum execution time: 6_940_000 picoseconds.
+		Weight::from_parts(7_432_469, 0)
+			.saturating_add(Weight::from_parts(0, 3703))
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(559, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	/// Storage: `Revive::ImmutableDataOf` (r:0 w:1)
+	/// Proof: `Revive::ImmutableDataOf` (`max_values`: None, `max_size`: Some(4118), added: 6593, mode: `Measured`)
+	/// The range of component `n` is `[1, 4096]`.
+	fn seal_set_immutable_data(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_360_000 picoseconds.
+		Weight::from_parts(2_609_078, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(563, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn seal_value_transferred() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 260_000 picoseconds.
+		Weight::from_parts(370_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_minimum_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 300_000 picoseconds.
+		Weight::from_parts(330_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_return_data_size() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 260_000 picoseconds.
+		Weight::from_parts(310_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_call_data_size() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 270_000 picoseconds.
+		Weight::from_parts(320_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_gas_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 520_000 picoseconds.
+		Weight::from_parts(600_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_gas_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 300_000 picoseconds.
+		Weight::from_parts(350_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_base_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 340_000 picoseconds.
+		Weight::from_parts(360_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_block_number() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 310_000 picoseconds.
+		Weight::from_parts(350_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Aura::Authorities` (r:1 w:0)
+	/// Proof: `Aura::Authorities` (`max_values`: Some(1), `max_size`: Some(3200004), added: 3200499, mode: `Measured`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn seal_block_author() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `343`
+		//  Estimated: `1828`
+		// Minimum execution time: 27_500_000 picoseconds.
+		Weight::from_parts(28_050_000, 0)
+			.saturating_add(Weight::from_parts(0, 1828))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `Measured`)
+	fn seal_block_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `3495`
+		// Minimum execution time: 4_210_000 picoseconds.
+		Weight::from_parts(4_370_000, 0)
+			.saturating_add(Weight::from_parts(0, 3495))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn seal_now() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 290_000 picoseconds.
+		Weight::from_parts(390_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn seal_weight_to_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_210_000 picoseconds.
+		Weight::from_parts(2_300_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `n` is `[0, 262140]`.
+	fn seal_copy_to_contract(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 510_000 picoseconds.
+		Weight::from_parts(714_388, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(236, 0).saturating_mul(n.into()))
+	}
+	fn seal_call_data_load() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 330_000 picoseconds.
+		Weight::from_parts(370_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn seal_call_data_copy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 280_000 picoseconds.
+		Weight::from_parts(405_894, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(156, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262140]`.
+	fn seal_return(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 340_000 picoseconds.
+		Weight::from_parts(543_695, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(235, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::DeletionQueueCounter` (r:1 w:1)
+	/// Proof: `Revive::DeletionQueueCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::DeletionQueue` (r:0 w:1)
+	/// Proof: `Revive::DeletionQueue` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `Measured`)
+	/// Storage: `Revive::ImmutableDataOf` (r:0 w:1)
+	/// Proof: `Revive::ImmutableDataOf` (`max_values`: None, `max_size`: Some(4118), added: 6593, mode: `Measured`)
+	fn seal_terminate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `3803`
+		// Minimum execution time: 17_500_000 picoseconds.
+		Weight::from_parts(17_840_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// The range of component `t` is `[0, 4]`.
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_deposit_event(t: u32, n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_180_000 picoseconds.
+		Weight::from_parts(5_058_973, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 2_296
+			.saturating_add(Weight::from_parts(253_808, 0).saturating_mul(t.into()))
+			// Standard Error: 25
+			.saturating_add(Weight::from_parts(1_185, 0).saturating_mul(n.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn get_storage_empty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653`
+		//  Estimated: `653`
+		// Minimum execution time: 9_360_000 picoseconds.
+		Weight::from_parts(9_610_000, 0)
+			.saturating_add(Weight::from_parts(0, 653))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn get_storage_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10663`
+		//  Estimated: `10663`
+		// Minimum execution time: 49_560_000 picoseconds.
+		Weight::from_parts(50_300_000, 0)
+			.saturating_add(Weight::from_parts(0, 10663))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_storage_empty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653`
+		//  Estimated: `653`
+		// Minimum execution time: 10_550_000 picoseconds.
+		Weight::from_parts(10_900_000, 0)
+			.saturating_add(Weight::from_parts(0, 653))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_storage_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10663`
+		//  Estimated: `10663`
+		// Minimum execution time: 51_120_000 picoseconds.
+		Weight::from_parts(51_670_000, 0)
+			.saturating_add(Weight::from_parts(0, 10663))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 416]`.
+	/// The range of component `o` is `[0, 416]`.
+	fn seal_set_storage(n: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + o * (1 ±0)`
+		//  Estimated: `252 + o * (1 ±0)`
+		// Minimum execution time: 11_520_000 picoseconds.
+		Weight::from_parts(11_839_672, 0)
+			.saturating_add(Weight::from_parts(0, 252))
+			// Standard Error: 32
+			.saturating_add(Weight::from_parts(559, 0).saturating_mul(n.into()))
+			// Standard Error: 32
+			.saturating_add(Weight::from_parts(554, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(o.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_clear_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + n * (1 ±0)`
+		//  Estimated: `252 + n * (1 ±0)`
+		// Minimum execution time: 11_010_000 picoseconds.
+		Weight::from_parts(11_824_727, 0)
+			.saturating_add(Weight::from_parts(0, 252))
+			// Standard Error: 51
+			.saturating_add(Weight::from_parts(449, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_get_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + n * (1 ±0)`
+		//  Estimated: `252 + n * (1 ±0)`
+		// Minimum execution time: 10_480_000 picoseconds.
+		Weight::from_parts(11_436_086, 0)
+			.saturating_add(Weight::from_parts(0, 252))
+			// Standard Error: 54
+			.saturating_add(Weight::from_parts(1_207, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_contains_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + n * (1 ±0)`
+		//  Estimated: `252 + n * (1 ±0)`
+		// Minimum execution time: 9_970_000 picoseconds.
+		Weight::from_parts(10_636_921, 0)
+			.saturating_add(Weight::from_parts(0, 252))
+			// Standard Error: 47
+			.saturating_add(Weight::from_parts(516, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_take_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + n * (1 ±0)`
+		//  Estimated: `252 + n * (1 ±0)`
+		// Minimum execution time: 11_790_000 picoseconds.
+		Weight::from_parts(12_640_945, 0)
+			.saturating_add(Weight::from_parts(0, 252))
+			// Standard Error: 60
+			.saturating_add(Weight::from_parts(1_213, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	fn set_transient_storage_empty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_810_000 picoseconds.
+		Weight::from_parts(1_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn set_transient_storage_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_410_000 picoseconds.
+		Weight::from_parts(2_560_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn get_transient_storage_empty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_830_000 picoseconds.
+		Weight::from_parts(1_900_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn get_transient_storage_full() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_140_000 picoseconds.
+		Weight::from_parts(2_250_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn rollback_transient_storage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_650_000 picoseconds.
+		Weight::from_parts(1_690_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `n` is `[0, 416]`.
+	/// The range of component `o` is `[0, 416]`.
+	fn seal_set_transient_storage(n: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_870_000 picoseconds.
+		Weight::from_parts(3_142_279, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 15
+			.saturating_add(Weight::from_parts(371, 0).saturating_mul(n.into()))
+			// Standard Error: 15
+			.saturating_add(Weight::from_parts(538, 0).saturating_mul(o.into()))
+	}
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_clear_transient_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_540_000 picoseconds.
+		Weight::from_parts(3_009_173, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 22
+			.saturating_add(Weight::from_parts(532, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_get_transient_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_290_000 picoseconds.
+		Weight::from_parts(2_553_723, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(507, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_contains_transient_storage(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_150_000 picoseconds.
+		Weight::from_parts(2_382_676, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(230, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 416]`.
+	fn seal_take_transient_storage(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_240_000 picoseconds.
+		Weight::from_parts(3_438_268, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// The range of component `t` is `[0, 1]`.
+	/// The range of component `i` is `[0, 262144]`.
+	fn seal_call(t: u32, _i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1289 + t * (280 ±0)`
+		//  Estimated: `4754 + t * (2517 ±0)`
+		// Minimum execution time: 37_540_000 picoseconds.
+		Weight::from_parts(38_714_608, 0)
+			.saturating_add(Weight::from_parts(0, 4754))
+			// Standard Error: 31_752
+			.saturating_add(Weight::from_parts(8_852_825, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(t.into()))
+	}
+	// FAIL-CI: dummy max weight, needs benchmark
+	fn seal_call_precompile(_: u32, _: u32, ) -> Weight {
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	fn seal_delegate_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1234`
+		//  Estimated: `4699`
+		// Minimum execution time: 32_580_000 picoseconds.
+		Weight::from_parts(33_210_000, 0)
+			.saturating_add(Weight::from_parts(0, 4699))
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	/// Storage: `Revive::PristineCode` (r:1 w:0)
+	/// Proof: `Revive::PristineCode` (`max_values`: None, `max_size`: Some(262180), added: 264655, mode: `Measured`)
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// The range of component `i` is `[0, 262144]`.
+	fn seal_instantiate(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1295`
+		//  Estimated: `4759`
+		// Minimum execution time: 131_530_000 picoseconds.
+		Weight::from_parts(139_258_888, 0)
+			.saturating_add(Weight::from_parts(0, 4759))
+			// Standard Error: 4
+			.saturating_add(Weight::from_parts(4_459, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn sha2_256(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 890_000 picoseconds.
+		Weight::from_parts(3_087_839, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(2_506, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn identity(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 360_000 picoseconds.
+		Weight::from_parts(518_630, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(156, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn ripemd_160(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 960_000 picoseconds.
+		Weight::from_parts(790_490, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 1
+			.saturating_add(Weight::from_parts(5_003, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn seal_hash_keccak_256(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_220_000 picoseconds.
+		Weight::from_parts(3_994_741, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 4
+			.saturating_add(Weight::from_parts(4_288, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn seal_hash_blake2_256(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 830_000 picoseconds.
+		Weight::from_parts(3_100_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_814, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 262144]`.
+	fn seal_hash_blake2_128(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 790_000 picoseconds.
+		Weight::from_parts(2_869_213, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_817, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[0, 261889]`.
+	fn seal_sr25519_verify(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 51_050_000 picoseconds.
+		Weight::from_parts(54_877_365, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 4
+			.saturating_add(Weight::from_parts(6_215, 0).saturating_mul(n.into()))
+	}
+	fn ecdsa_recover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 54_210_000 picoseconds.
+		Weight::from_parts(54_550_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn bn128_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 17_510_000 picoseconds.
+		Weight::from_parts(17_700_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn bn128_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_019_632_000 picoseconds.
+		Weight::from_parts(1_022_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `n` is `[0, 1365]`.
+	fn bn128_pairing(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_138_394_000 picoseconds.
+		Weight::from_parts(13_183_638_570, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `n` is `[0, 1200]`.
+	fn blake2f(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 440_000 picoseconds.
+		Weight::from_parts(597_380, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 32
+			.saturating_add(Weight::from_parts(30_348, 0).saturating_mul(n.into()))
+	}
+	fn seal_ecdsa_to_eth_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_600_000 picoseconds.
+		Weight::from_parts(12_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `Revive::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Revive::CodeInfoOf` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `Measured`)
+	fn seal_set_code_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3763`
+		// Minimum execution time: 12_500_000 picoseconds.
+		Weight::from_parts(12_760_000, 0)
+			.saturating_add(Weight::from_parts(0, 3763))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// The range of component `r` is `[0, 10000]`.
+	fn instr(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_270_000 picoseconds.
+		Weight::from_parts(59_464_908, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 181
+			.saturating_add(Weight::from_parts(182_355, 0).saturating_mul(r.into()))
+	}
+	/// The range of component `r` is `[0, 100000]`.
+	fn instr_empty_loop(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_800_000 picoseconds.
+		Weight::from_parts(6_527_560, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(84_054, 0).saturating_mul(r.into()))
+	}
+}
@@ -56,6 +56,7 @@ use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
 use fellowship::{pallet_fellowship_origins, Fellows};
 use impls::{AllianceProposalProvider, EqualOrGreatestRootCmp, ToParentTreasury};
+use pallet_proxy::ProxyDefinition;
 use polkadot_runtime_common::impls::{
 	ContainsParts as ContainsLocationParts, VersionedLocatableAsset,
 };
@@ -63,7 +64,7 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT},
+	traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill,
 };
@@ -81,8 +82,8 @@ use frame_support::{
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstBool, ConstU16, ConstU32, ConstU64, ConstU8, EitherOfDiverse, FromContains,
-		InstanceFilter, LinearStoragePrice, TransformOrigin,
+		ConstBool, ConstU16, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Equals, FromContains,
+		InstanceFilter, LinearStoragePrice, PalletInfoAccess, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
@@ -96,7 +97,14 @@ use parachains_common::{
 };
 use sp_runtime::RuntimeDebug;
 use system_parachains_constants::{
-	polkadot::{account::*, consensus::*, currency::*, fee::WeightToFee},
+	polkadot::{
+		account::*,
+		consensus::*,
+		currency::*,
+		fee::WeightToFee,
+		locations::AssetHubLocation,
+		proxy::{AssetHubProxyType, AssetHubRemoteProxyInterface},
+	},
 	AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MINUTES, NORMAL_DISPATCH_RATIO,
 	SLOT_DURATION,
 };
@@ -346,6 +354,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility(_) |
 					RuntimeCall::Multisig(_) |
 					RuntimeCall::Proxy(_) |
+					RuntimeCall::RemoteProxyRelayChain(_) |
+					RuntimeCall::RemoteProxyAssetHub(_) |
 					RuntimeCall::Preimage(_) |
 					RuntimeCall::Alliance(_) |
 					RuntimeCall::AllianceMotion(_) |
@@ -363,7 +373,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::CancelProxy => matches!(
 				c,
 				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
-					RuntimeCall::Utility { .. } |
+					RuntimeCall::RemoteProxyRelayChain(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::RemoteProxyAssetHub(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
 			ProxyType::Collator => matches!(
@@ -433,6 +447,139 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+/// Converts from the relay chain proxy type to the local proxy type.
+pub struct RelayChainToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for RelayChainToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			polkadot_runtime_constants::proxy::ProxyType::Any => ProxyType::Any,
+			polkadot_runtime_constants::proxy::ProxyType::NonTransfer => ProxyType::NonTransfer,
+			polkadot_runtime_constants::proxy::ProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the Collectives chain.
+			polkadot_runtime_constants::proxy::ProxyType::Governance |
+			polkadot_runtime_constants::proxy::ProxyType::Staking |
+			polkadot_runtime_constants::proxy::ProxyType::Auction |
+			polkadot_runtime_constants::proxy::ProxyType::NominationPools |
+			polkadot_runtime_constants::proxy::ProxyType::ParaRegistration |
+			polkadot_runtime_constants::proxy::ProxyType::Spokesperson |
+			polkadot_runtime_constants::proxy::ProxyType::ParaManagement |
+			polkadot_runtime_constants::proxy::ProxyType::OnDemandOrdering => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is already converted to local blocks by the remote proxy interface.
+			delay: a.delay,
+		})
+	}
+}
+
+/// The maximum age of a remote proxy proof per local proxy type.
+///
+/// Proofs of the most powerful proxy types are only accepted against the storage roots of the last
+/// few relay chain blocks, which leaves enough time to get a transaction with a fresh proof
+/// included. So, a proxy removed on the relay chain can not use older proofs for long. All other
+/// proxy types may use any of the kept storage roots.
+pub struct RemoteProxyMaxProofAge;
+
+impl Convert<ProxyType, u32> for RemoteProxyMaxProofAge {
+	fn convert(proxy_type: ProxyType) -> u32 {
+		match proxy_type {
+			ProxyType::Any | ProxyType::NonTransfer => 3,
+			ProxyType::CancelProxy |
+			ProxyType::Collator |
+			ProxyType::Alliance |
+			ProxyType::Fellowship |
+			ProxyType::Ambassador |
+			ProxyType::Secretary => polkadot_runtime_constants::time::MINUTES,
+		}
+	}
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// The relay chain can push revocations of its proxies.
+	type RevocationOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<xcm_config::DotLocation>>>;
+	type MaxRevocations = ConstU32<64>;
+	type RemoteProxy = polkadot_runtime_constants::proxy::RemoteProxyInterface<
+		ProxyType,
+		RelayChainToLocalProxyTypeConverter,
+		// The local proxy pallet uses the local block number.
+		ConstU64<{ system_parachains_constants::MILLISECS_PER_BLOCK }>,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy_remote_proxy_relay_chain::WeightInfo<Runtime>;
+}
+
+/// Converts from the Asset Hub proxy type to the local proxy type.
+pub struct AssetHubToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, AssetHubProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for AssetHubToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, AssetHubProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			AssetHubProxyType::Any => ProxyType::Any,
+			AssetHubProxyType::NonTransfer => ProxyType::NonTransfer,
+			AssetHubProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the Collectives chain.
+			AssetHubProxyType::Assets |
+			AssetHubProxyType::AssetOwner |
+			AssetHubProxyType::AssetManager |
+			AssetHubProxyType::Collator => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is already converted to local blocks by the remote proxy interface.
+			delay: a.delay,
+		})
+	}
+}
+
+/// The instance of the remote proxy pallet that uses Asset Hub as remote location.
+pub type RemoteProxyAssetHubInstance = pallet_remote_proxy::Instance1;
+
+impl pallet_remote_proxy::Config<RemoteProxyAssetHubInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// Asset Hub can push revocations of its proxies.
+	type RevocationOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<AssetHubLocation>>>;
+	type MaxRevocations = ConstU32<64>;
+	type RemoteProxy = AssetHubRemoteProxyInterface<
+		ProxyType,
+		AssetHubToLocalProxyTypeConverter,
+		// The local proxy pallet uses the local block number.
+		ConstU64<{ system_parachains_constants::MILLISECS_PER_BLOCK }>,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy_remote_proxy_asset_hub::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -441,7 +588,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = (RemoteProxyRelayChain, RemoteProxyAssetHub);
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -769,6 +916,8 @@ construct_runtime!(
 		Preimage: pallet_preimage = 43,
 		Scheduler: pallet_scheduler = 44,
 		AssetRate: pallet_asset_rate = 45,
+		RemoteProxyRelayChain: pallet_remote_proxy = 46,
+		RemoteProxyAssetHub: pallet_remote_proxy::<Instance1> = 47,
 
 		// The main stage.
 
@@ -871,7 +1020,6 @@ pub type Executive = frame_executive::Executive<
 mod benches {
 	use super::*;
 	use polkadot_runtime_constants::system_parachain::AssetHubParaId;
-	use system_parachains_constants::polkadot::locations::AssetHubLocation;
 
 	frame_benchmarking::define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
@@ -880,6 +1028,8 @@ mod benches {
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
+		[pallet_remote_proxy, RemoteProxyRelayChain]
+		[pallet_remote_proxy, RemoteProxyAssetHub]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_utility, Utility]
 		[pallet_timestamp, Timestamp]
@@ -1097,6 +1247,13 @@ mod benches {
 #[cfg(feature = "runtime-benchmarks")]
 use benches::*;
 
+/// The tracks of the fellowship referenda pallet.
+type FellowshipReferendaTracks =
+	<Runtime as pallet_referenda::Config<fellowship::FellowshipReferendaInstance>>::Tracks;
+/// The tracks of the ambassador referenda pallet.
+type AmbassadorReferendaTracks =
+	<Runtime as pallet_referenda::Config<ambassador::AmbassadorReferendaInstance>>::Tracks;
+
 impl_runtime_apis! {
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -1123,6 +1280,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl relay_common::apis::ReferendaTracks<Block, u16, Balance, BlockNumber> for Runtime {
+		fn referenda_tracks(
+			pallet_index: u8,
+		) -> Option<Vec<relay_common::apis::ReferendaTrack<u16, Balance, BlockNumber>>> {
+			use relay_common::referenda::tracks;
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					Some(tracks::<FellowshipReferendaTracks, _, _>()),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					Some(tracks::<AmbassadorReferendaTracks, _, _>()),
+				_ => None,
+			}
+		}
+
+		fn referenda_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: Perbill,
+		) -> Option<relay_common::apis::ReferendaThresholds> {
+			use relay_common::referenda::thresholds;
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					thresholds::<FellowshipReferendaTracks, _, _>(track, elapsed),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					thresholds::<AmbassadorReferendaTracks, _, _>(track, elapsed),
+				_ => None,
+			}
+		}
+
+		fn referenda_time_to_pass(
+			pallet_index: u8,
+			track: u16,
+			approval: Perbill,
+			support: Perbill,
+		) -> Option<BlockNumber> {
+			use relay_common::referenda::time_to_pass;
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					time_to_pass::<FellowshipReferendaTracks, _, _>(track, approval, support),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					time_to_pass::<AmbassadorReferendaTracks, _, _>(track, approval, support),
+				_ => None,
+			}
+		}
+	}
+
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
 			VERSION
@@ -1317,6 +1520,56 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<
+		Block,
+		AccountId,
+		ProxyType,
+		BlockNumber,
+		BlockNumber,
+	> for Runtime
+	{
+		fn remote_storage_keys(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+		) -> Option<pallet_remote_proxy::RemoteProxyStorageKeys> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::remote_storage_keys(&real)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::remote_storage_keys(&real)
+			} else {
+				None
+			}
+		}
+
+		fn acceptable_anchor_blocks(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			proxy_type: Option<ProxyType>,
+		) -> Vec<BlockNumber> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::acceptable_anchor_blocks(proxy_type)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::acceptable_anchor_blocks(proxy_type)
+			} else {
+				Vec::new()
+			}
+		}
+
+		fn validate_proof(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+			delegate: AccountId,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<Vec<pallet_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>>, sp_runtime::DispatchError> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else {
+				Err(pallet_remote_proxy::Error::<Runtime>::UnsupportedProofKind.into())
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
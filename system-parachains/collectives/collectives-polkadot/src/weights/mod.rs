@@ -31,6 +31,8 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_remote_proxy_remote_proxy_relay_chain;
+pub mod pallet_remote_proxy_remote_proxy_asset_hub;
 pub mod pallet_ranked_collective_ambassador_collective;
 pub mod pallet_ranked_collective_fellowship_collective;
 pub mod pallet_ranked_collective_secretary_collective;
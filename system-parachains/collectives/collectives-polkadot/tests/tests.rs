@@ -1,7 +1,9 @@
-use collectives_polkadot_runtime::{xcm_config::GovernanceLocation, Runtime, RuntimeOrigin};
+use collectives_polkadot_runtime::{
+	xcm_config::GovernanceLocation, ProxyType, Runtime, RuntimeOrigin,
+};
 use frame_support::{assert_err, assert_ok};
 use parachains_runtimes_test_utils::GovernanceOrigin;
-use sp_runtime::Either;
+use sp_runtime::{traits::Convert, Either};
 use xcm::prelude::*;
 
 #[test]
@@ -54,3 +56,59 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+/// Proofs of the most powerful proxy types are only accepted against the latest few storage roots.
+#[test]
+fn remote_proxy_max_proof_age_depends_on_proxy_type() {
+	use frame_support::traits::Get;
+
+	type MaxProofAge = <Runtime as pallet_remote_proxy::Config>::MaxProofAge;
+	let max_roots: u32 = <Runtime as pallet_remote_proxy::Config>::MaxStorageRootsToKeep::get();
+
+	assert_eq!(MaxProofAge::convert(ProxyType::Any), 3);
+	assert_eq!(MaxProofAge::convert(ProxyType::NonTransfer), 3);
+	for proxy_type in [
+		ProxyType::CancelProxy,
+		ProxyType::Collator,
+		ProxyType::Alliance,
+		ProxyType::Fellowship,
+		ProxyType::Ambassador,
+		ProxyType::Secretary,
+	] {
+		assert_eq!(MaxProofAge::convert(proxy_type), max_roots);
+	}
+}
+
+#[test]
+fn referenda_tracks_api_works() {
+	use collectives_polkadot_runtime::{AmbassadorReferenda, Balances, FellowshipReferenda};
+	use frame_support::traits::PalletInfoAccess;
+	use relay_common::apis::runtime_decl_for_referenda_tracks::ReferendaTracksV1;
+	use sp_runtime::Perbill;
+	use system_parachains_constants::{HOURS, MINUTES};
+
+	let fellowship = FellowshipReferenda::index() as u8;
+	let ambassador = AmbassadorReferenda::index() as u8;
+	let members = 1;
+
+	let tracks = Runtime::referenda_tracks(fellowship).unwrap();
+	assert_eq!(tracks.len(), 24);
+	assert_eq!((tracks[0].id, tracks[0].name.as_str()), (members, "members"));
+
+	let tracks = Runtime::referenda_tracks(ambassador).unwrap();
+	assert_eq!(
+		tracks.iter().map(|t| (t.id, t.name.as_str())).collect::<Vec<_>>(),
+		vec![(1, "ambassador"), (2, "senior ambassador"), (3, "head ambassador")]
+	);
+	assert_eq!(Runtime::referenda_tracks(Balances::index() as u8), None);
+
+	assert!(Runtime::referenda_thresholds(fellowship, members, Perbill::zero()).is_some());
+	assert_eq!(Runtime::referenda_thresholds(fellowship, 99, Perbill::zero()), None);
+
+	// A unanimous referendum only has to be confirmed.
+	let unanimous = |pallet_index, track| {
+		Runtime::referenda_time_to_pass(pallet_index, track, Perbill::one(), Perbill::one())
+	};
+	assert_eq!(unanimous(ambassador, 1), Some(24 * HOURS));
+	assert_eq!(unanimous(fellowship, members), Some(30 * MINUTES));
+}
@@ -179,3 +179,190 @@ pub mod locations {
 		pub EthereumNetwork: NetworkId = NetworkId::Ethereum { chain_id: 1 };
 	}
 }
+
+pub mod proxy {
+	use frame_support::traits::{ConstU64, Get};
+	use pallet_remote_proxy::{ParaId, ProxyDefinition};
+	use polkadot_primitives::{AccountId, BlakeTwo256, BlockNumber, Hash, Moment};
+	use polkadot_runtime_constants::{system_parachain::ASSET_HUB_ID, time::MILLISECS_PER_BLOCK};
+	use sp_runtime::traits::Convert;
+
+	/// The kinds of proxying allowed on Asset Hub Polkadot.
+	///
+	/// Needs to match the encoding of the `ProxyType` of the Asset Hub Polkadot runtime, as it is
+	/// used to decode the proxy definitions of Asset Hub in remote proxy proofs.
+	#[derive(
+		Copy,
+		Clone,
+		Eq,
+		PartialEq,
+		Ord,
+		PartialOrd,
+		codec::Encode,
+		codec::Decode,
+		codec::DecodeWithMemTracking,
+		core::fmt::Debug,
+		codec::MaxEncodedLen,
+		scale_info::TypeInfo,
+		Default,
+	)]
+	pub enum AssetHubProxyType {
+		#[default]
+		Any = 0,
+		NonTransfer = 1,
+		CancelProxy = 2,
+		Assets = 3,
+		AssetOwner = 4,
+		AssetManager = 5,
+		Collator = 6,
+	}
+
+	/// Remote proxy interface that uses Asset Hub Polkadot as remote location.
+	///
+	/// The proxy definitions are proven against the state of Asset Hub, whose head is proven
+	/// against the relay chain storage roots. The proxy pallet of Asset Hub uses the relay chain
+	/// block number, so delays are converted from relay chain blocks.
+	///
+	/// `ProxyDefinitionConverter` receives the Asset Hub proxy definition with its `delay` already
+	/// converted to local blocks. `LocalMillisecsPerBlock` is the block time of the block number
+	/// provider used by the local proxy pallet.
+	pub struct AssetHubRemoteProxyInterface<
+		LocalProxyType,
+		ProxyDefinitionConverter,
+		LocalMillisecsPerBlock = ConstU64<MILLISECS_PER_BLOCK>,
+	>(
+		core::marker::PhantomData<(
+			LocalProxyType,
+			ProxyDefinitionConverter,
+			LocalMillisecsPerBlock,
+		)>,
+	);
+
+	impl<
+			LocalProxyType,
+			ProxyDefinitionConverter: Convert<
+				ProxyDefinition<AccountId, AssetHubProxyType, BlockNumber>,
+				Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>>,
+			>,
+			LocalMillisecsPerBlock: Get<Moment>,
+		> pallet_remote_proxy::RemoteProxyInterface<AccountId, LocalProxyType, BlockNumber>
+		for AssetHubRemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter, LocalMillisecsPerBlock>
+	{
+		type RemoteAccountId = AccountId;
+
+		type RemoteProxyType = AssetHubProxyType;
+
+		type RemoteBlockNumber = BlockNumber;
+
+		type RemoteHash = Hash;
+
+		type RemoteHasher = BlakeTwo256;
+
+		fn block_to_storage_root(
+			validation_data: &polkadot_primitives::PersistedValidationData,
+		) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as sp_core::Hasher>::Out)> {
+			Some((validation_data.relay_parent_number, validation_data.relay_parent_storage_root))
+		}
+
+		fn remote_para_id() -> Option<ParaId> {
+			Some(ASSET_HUB_ID.into())
+		}
+
+		fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId> {
+			Some(local.clone())
+		}
+
+		fn remote_to_local_delay(delay: Self::RemoteBlockNumber) -> Option<BlockNumber> {
+			let local_millisecs_per_block = LocalMillisecsPerBlock::get();
+			if local_millisecs_per_block == 0 {
+				return None;
+			}
+
+			let millisecs = Moment::from(delay).checked_mul(MILLISECS_PER_BLOCK)?;
+			// Round up to never shorten the delay.
+			millisecs.div_ceil(local_millisecs_per_block).try_into().ok()
+		}
+
+		fn remote_to_local_proxy_defintion(
+			remote: ProxyDefinition<
+				Self::RemoteAccountId,
+				Self::RemoteProxyType,
+				Self::RemoteBlockNumber,
+			>,
+		) -> Option<ProxyDefinition<AccountId, LocalProxyType, BlockNumber>> {
+			let delay = Self::remote_to_local_delay(remote.delay)?;
+
+			ProxyDefinitionConverter::convert(ProxyDefinition { delay, ..remote })
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_remote_proxy_proof(
+			caller: &AccountId,
+			proxy: &AccountId,
+		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
+			Self::create_remote_proxy_batch_proof(caller, core::slice::from_ref(proxy))
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn create_remote_proxy_batch_proof(
+			caller: &AccountId,
+			proxies: &[AccountId],
+		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
+			use codec::Encode;
+			use sp_runtime::traits::Header as _;
+			use sp_trie::TrieMut;
+
+			// The state of Asset Hub with the proxy definitions.
+			let (mut para_db, mut para_root) =
+				sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+			let mut trie = sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(
+				&mut para_db,
+				&mut para_root,
+			)
+			.build();
+
+			let proxy_definition =
+				alloc::vec![ProxyDefinition::<AccountId, AssetHubProxyType, BlockNumber> {
+					delegate: caller.clone(),
+					proxy_type: AssetHubProxyType::default(),
+					delay: 0,
+				}];
+
+			for proxy in proxies {
+				trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+					.unwrap();
+			}
+			drop(trie);
+
+			// The relay chain state with the head of Asset Hub.
+			let head = sp_runtime::generic::Header::<BlockNumber, BlakeTwo256>::new(
+				1,
+				Default::default(),
+				para_root,
+				Default::default(),
+				Default::default(),
+			);
+			let (mut relay_db, mut relay_root) =
+				sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+			let mut trie = sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(
+				&mut relay_db,
+				&mut relay_root,
+			)
+			.build();
+			// `HeadData` is encoded as a plain vector of bytes.
+			trie.insert(&Self::para_head_storage_key(ASSET_HUB_ID.into()), &head.encode().encode())
+				.unwrap();
+			drop(trie);
+
+			(
+				pallet_remote_proxy::RemoteProxyProof::Parachain {
+					relay_proof: relay_db.drain().into_values().map(|d| d.0).collect(),
+					proof: para_db.drain().into_values().map(|d| d.0).collect(),
+					block: 1,
+				},
+				1,
+				relay_root,
+			)
+		}
+	}
+}
@@ -297,3 +297,24 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+/// Proofs of the most powerful proxy types are only accepted against the latest few storage roots.
+#[test]
+fn remote_proxy_max_proof_age_depends_on_proxy_type() {
+	use frame_support::traits::Get;
+
+	type MaxProofAge = <Runtime as pallet_remote_proxy::Config>::MaxProofAge;
+	let max_roots: u32 = <Runtime as pallet_remote_proxy::Config>::MaxStorageRootsToKeep::get();
+
+	assert_eq!(MaxProofAge::convert(ProxyType::Any), 3);
+	assert_eq!(MaxProofAge::convert(ProxyType::NonTransfer), 3);
+	for proxy_type in [
+		ProxyType::CancelProxy,
+		ProxyType::Broker,
+		ProxyType::CoretimeRenewer,
+		ProxyType::OnDemandPurchaser,
+		ProxyType::Collator,
+	] {
+		assert_eq!(MaxProofAge::convert(proxy_type), max_roots);
+	}
+}
@@ -28,6 +28,8 @@ pub mod pallet_collator_selection;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_proxy;
+pub mod pallet_remote_proxy_remote_proxy_relay_chain;
+pub mod pallet_remote_proxy_remote_proxy_asset_hub;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
@@ -39,7 +39,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::imbalance::ResolveTo, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
-		Everything, InstanceFilter, TransformOrigin,
+		Equals, Everything, InstanceFilter, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
@@ -48,6 +48,7 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
 };
+use pallet_proxy::ProxyDefinition;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::{
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
@@ -62,7 +63,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 pub use sp_runtime::BuildStorage;
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{BlakeTwo256, Block as BlockT},
+	traits::{BlakeTwo256, Block as BlockT, Convert},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, RuntimeDebug,
 };
@@ -70,7 +71,13 @@ pub use sp_runtime::{MultiAddress, Perbill, Permill};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
-use system_parachains_constants::polkadot::{consensus::*, currency::*, fee::WeightToFee};
+use system_parachains_constants::polkadot::{
+	consensus::*,
+	currency::*,
+	fee::WeightToFee,
+	locations::AssetHubLocation,
+	proxy::{AssetHubProxyType, AssetHubRemoteProxyInterface},
+};
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 use xcm::{
 	latest::prelude::{AssetId, BodyId},
@@ -280,7 +287,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = (RemoteProxyRelayChain, RemoteProxyAssetHub);
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type OutboundXcmpMessageSource = XcmpQueue;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
@@ -489,6 +496,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility(_) |
 					RuntimeCall::Multisig(_) |
 					RuntimeCall::Proxy(_) |
+					RuntimeCall::RemoteProxyRelayChain(_) |
+					RuntimeCall::RemoteProxyAssetHub(_) |
 					// We don't allow:
 					// `request_judgement` puts up a deposit to transfer to a registrar,
 					// `set_subs` and `add_sub` will take and repatriate deposits from the proxied
@@ -516,7 +525,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::CancelProxy => matches!(
 				c,
 				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
-					RuntimeCall::Utility { .. } |
+					RuntimeCall::RemoteProxyRelayChain(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::RemoteProxyAssetHub(
+						pallet_remote_proxy::Call::reject_announcement { .. }
+					) | RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
 			ProxyType::Identity => {
@@ -583,6 +596,137 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+/// Converts from the relay chain proxy type to the local proxy type.
+pub struct RelayChainToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for RelayChainToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, polkadot_runtime_constants::proxy::ProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			polkadot_runtime_constants::proxy::ProxyType::Any => ProxyType::Any,
+			polkadot_runtime_constants::proxy::ProxyType::NonTransfer => ProxyType::NonTransfer,
+			polkadot_runtime_constants::proxy::ProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the People chain.
+			polkadot_runtime_constants::proxy::ProxyType::Governance |
+			polkadot_runtime_constants::proxy::ProxyType::Staking |
+			polkadot_runtime_constants::proxy::ProxyType::Auction |
+			polkadot_runtime_constants::proxy::ProxyType::NominationPools |
+			polkadot_runtime_constants::proxy::ProxyType::ParaRegistration |
+			polkadot_runtime_constants::proxy::ProxyType::Spokesperson |
+			polkadot_runtime_constants::proxy::ProxyType::ParaManagement |
+			polkadot_runtime_constants::proxy::ProxyType::OnDemandOrdering => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is already converted to local blocks by the remote proxy interface.
+			delay: a.delay,
+		})
+	}
+}
+
+/// The maximum age of a remote proxy proof per local proxy type.
+///
+/// Proofs of the most powerful proxy types are only accepted against the storage roots of the last
+/// few relay chain blocks, which leaves enough time to get a transaction with a fresh proof
+/// included. So, a proxy removed on the relay chain can not use older proofs for long. All other
+/// proxy types may use any of the kept storage roots.
+pub struct RemoteProxyMaxProofAge;
+
+impl Convert<ProxyType, u32> for RemoteProxyMaxProofAge {
+	fn convert(proxy_type: ProxyType) -> u32 {
+		match proxy_type {
+			ProxyType::Any | ProxyType::NonTransfer => 3,
+			ProxyType::CancelProxy |
+			ProxyType::Identity |
+			ProxyType::IdentityJudgement |
+			ProxyType::Collator => polkadot_runtime_constants::time::MINUTES,
+		}
+	}
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// The relay chain can push revocations of its proxies.
+	type RevocationOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<xcm_config::RelayLocation>>>;
+	type MaxRevocations = ConstU32<64>;
+	type RemoteProxy = polkadot_runtime_constants::proxy::RemoteProxyInterface<
+		ProxyType,
+		RelayChainToLocalProxyTypeConverter,
+		// The local proxy pallet uses the local block number.
+		ConstU64<{ system_parachains_constants::MILLISECS_PER_BLOCK }>,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy_remote_proxy_relay_chain::WeightInfo<Runtime>;
+}
+
+/// Converts from the Asset Hub proxy type to the local proxy type.
+pub struct AssetHubToLocalProxyTypeConverter;
+
+impl
+	Convert<
+		ProxyDefinition<AccountId, AssetHubProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for AssetHubToLocalProxyTypeConverter
+{
+	fn convert(
+		a: ProxyDefinition<AccountId, AssetHubProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = match a.proxy_type {
+			AssetHubProxyType::Any => ProxyType::Any,
+			AssetHubProxyType::NonTransfer => ProxyType::NonTransfer,
+			AssetHubProxyType::CancelProxy => ProxyType::CancelProxy,
+			// Proxy types that are not supported on the People chain.
+			AssetHubProxyType::Assets |
+			AssetHubProxyType::AssetOwner |
+			AssetHubProxyType::AssetManager |
+			AssetHubProxyType::Collator => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: a.delegate,
+			proxy_type,
+			// The delay is already converted to local blocks by the remote proxy interface.
+			delay: a.delay,
+		})
+	}
+}
+
+/// The instance of the remote proxy pallet that uses Asset Hub as remote location.
+pub type RemoteProxyAssetHubInstance = pallet_remote_proxy::Instance1;
+
+impl pallet_remote_proxy::Config<RemoteProxyAssetHubInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	type MaxProofAccounts = ConstU32<64>;
+	type MaxProofAccountsPerSigner = ConstU32<16>;
+	type MaxProofAge = RemoteProxyMaxProofAge;
+	// Asset Hub can push revocations of its proxies.
+	type RevocationOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<AssetHubLocation>>>;
+	type MaxRevocations = ConstU32<64>;
+	type RemoteProxy = AssetHubRemoteProxyInterface<
+		ProxyType,
+		AssetHubToLocalProxyTypeConverter,
+		// The local proxy pallet uses the local block number.
+		ConstU64<{ system_parachains_constants::MILLISECS_PER_BLOCK }>,
+	>;
+	type WeightInfo = weights::pallet_remote_proxy_remote_proxy_asset_hub::WeightInfo<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -641,6 +785,8 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteProxyRelayChain: pallet_remote_proxy = 43,
+		RemoteProxyAssetHub: pallet_remote_proxy::<Instance1> = 44,
 
 		// The main stage.
 		Identity: pallet_identity = 50,
@@ -652,7 +798,6 @@ mod benches {
 	use super::*;
 	use alloc::boxed::Box;
 	use polkadot_runtime_constants::system_parachain::AssetHubParaId;
-	use system_parachains_constants::polkadot::locations::AssetHubLocation;
 
 	frame_benchmarking::define_benchmarks!(
 		// Substrate
@@ -664,6 +809,8 @@ mod benches {
 		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
+		[pallet_remote_proxy, RemoteProxyRelayChain]
+		[pallet_remote_proxy, RemoteProxyAssetHub]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_timestamp, Timestamp]
@@ -1079,6 +1226,56 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_remote_proxy::runtime_api::RemoteProxyApi<
+		Block,
+		AccountId,
+		ProxyType,
+		BlockNumber,
+		BlockNumber,
+	> for Runtime
+	{
+		fn remote_storage_keys(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+		) -> Option<pallet_remote_proxy::RemoteProxyStorageKeys> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::remote_storage_keys(&real)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::remote_storage_keys(&real)
+			} else {
+				None
+			}
+		}
+
+		fn acceptable_anchor_blocks(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			proxy_type: Option<ProxyType>,
+		) -> Vec<BlockNumber> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::acceptable_anchor_blocks(proxy_type)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::acceptable_anchor_blocks(proxy_type)
+			} else {
+				Vec::new()
+			}
+		}
+
+		fn validate_proof(
+			remote_para_id: Option<pallet_remote_proxy::ParaId>,
+			real: AccountId,
+			delegate: AccountId,
+			proof: pallet_remote_proxy::RemoteProxyProof<BlockNumber>,
+		) -> Result<Vec<pallet_proxy::ProxyDefinition<AccountId, ProxyType, BlockNumber>>, sp_runtime::DispatchError> {
+			if remote_para_id == RemoteProxyRelayChain::remote_para_id() {
+				RemoteProxyRelayChain::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else if remote_para_id == RemoteProxyAssetHub::remote_para_id() {
+				RemoteProxyAssetHub::validate_remote_proxy_proof(&real, &delegate, proof)
+			} else {
+				Err(pallet_remote_proxy::Error::<Runtime>::UnsupportedProofKind.into())
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -16,7 +16,7 @@
 
 use crate::{
 	xcm_config::{GovernanceLocation, LocationToAccountId},
-	Block, Runtime, RuntimeCall, RuntimeOrigin, WeightToFee,
+	Block, ProxyType, Runtime, RuntimeCall, RuntimeOrigin, WeightToFee,
 };
 use cumulus_primitives_core::relay_chain::AccountId;
 use sp_core::crypto::Ss58Codec;
@@ -25,7 +25,7 @@ use xcm_runtime_apis::conversions::LocationToAccountHelper;
 
 use frame_support::{assert_err, assert_ok};
 use parachains_runtimes_test_utils::GovernanceOrigin;
-use sp_runtime::Either;
+use sp_runtime::{traits::Convert, Either};
 
 const ALICE: [u8; 32] = [1u8; 32];
 
@@ -190,3 +190,23 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+/// Proofs of the most powerful proxy types are only accepted against the latest few storage roots.
+#[test]
+fn remote_proxy_max_proof_age_depends_on_proxy_type() {
+	use frame_support::traits::Get;
+
+	type MaxProofAge = <Runtime as pallet_remote_proxy::Config>::MaxProofAge;
+	let max_roots: u32 = <Runtime as pallet_remote_proxy::Config>::MaxStorageRootsToKeep::get();
+
+	assert_eq!(MaxProofAge::convert(ProxyType::Any), 3);
+	assert_eq!(MaxProofAge::convert(ProxyType::NonTransfer), 3);
+	for proxy_type in [
+		ProxyType::CancelProxy,
+		ProxyType::Identity,
+		ProxyType::IdentityJudgement,
+		ProxyType::Collator,
+	] {
+		assert_eq!(MaxProofAge::convert(proxy_type), max_roots);
+	}
+}